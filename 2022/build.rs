@@ -0,0 +1,4 @@
+/// Turns every line of `answers` into a test, included by `tests/answers.rs`.
+fn main() {
+    aoc_utils::answers::generate();
+}
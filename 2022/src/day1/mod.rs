@@ -0,0 +1,106 @@
+use anyhow::Context;
+use aoc_utils::{parse_number, Answer, ParseError, Solution};
+
+/// The calories each elf carries: blank lines split the elves, every other line
+/// is some calories.
+pub fn parse_groups(input: &str) -> Result<Vec<Vec<usize>>, ParseError> {
+    let mut groups: Vec<Vec<usize>> = vec![];
+    for line in input.lines() {
+        if line.is_empty() {
+            groups.push(vec![]);
+            continue;
+        }
+        let calories = parse_number(input, line)?;
+        match groups.last_mut() {
+            Some(last) => last.push(calories),
+            None => groups.push(vec![calories]),
+        }
+    }
+    Ok(groups)
+}
+
+pub fn max_sum_of_grouped_lines(groups: &[Vec<usize>]) -> Option<usize> {
+    groups.iter().map(|group| group.iter().sum()).max()
+}
+
+pub fn max_sum_of_three_best_grouped_lines(groups: &[Vec<usize>]) -> Option<usize> {
+    let mut vals: Vec<usize> = groups.iter().map(|group| group.iter().sum()).collect();
+
+    if vals.is_empty() {
+        return None;
+    }
+
+    vals.sort();
+
+    Some(vals.iter().rev().take(3).copied().sum())
+}
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    type Input = Vec<Vec<usize>>;
+
+    fn parse(input: &str) -> anyhow::Result<Self::Input> {
+        Ok(parse_groups(input)?)
+    }
+
+    fn part1(groups: &Self::Input) -> anyhow::Result<Answer> {
+        Ok(max_sum_of_grouped_lines(groups)
+            .context("no elves in the input")?
+            .into())
+    }
+
+    fn part2(groups: &Self::Input) -> anyhow::Result<Answer> {
+        Ok(max_sum_of_three_best_grouped_lines(groups)
+            .context("no elves in the input")?
+            .into())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use aoc_utils::Solution;
+
+    use super::{max_sum_of_grouped_lines, parse_groups, Day1};
+
+    fn groups(input: &str) -> Vec<Vec<usize>> {
+        parse_groups(input).unwrap()
+    }
+
+    #[test]
+    fn test_when_empty() {
+        assert!(max_sum_of_grouped_lines(&groups("")).is_none());
+    }
+
+    #[test]
+    fn test_when_empty_lines() {
+        assert!(max_sum_of_grouped_lines(&groups("\n\n\n")).unwrap() == 0)
+    }
+
+    #[test]
+    fn test_simple_one() {
+        assert_eq!(max_sum_of_grouped_lines(&groups("13")).unwrap(), 13)
+    }
+
+    #[test]
+    fn test_simple_two() {
+        assert_eq!(max_sum_of_grouped_lines(&groups("13\n\n17")).unwrap(), 17)
+    }
+
+    #[test]
+    fn test_global() {
+        assert_eq!(
+            max_sum_of_grouped_lines(&groups("\n\n13\n23\n\n\n17\n21\n\n")).unwrap(),
+            38 // the sum of 17+21
+        )
+    }
+
+    #[test]
+    fn bad_calories() {
+        let err = Day1::parse("1000\n2000\n\n3000 \n").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 4, column 1: expected a number, found \"3000 \""
+        );
+    }
+}
@@ -0,0 +1,571 @@
+use std::{
+    fmt::{self, Debug, Display},
+    rc::Rc,
+    str::FromStr,
+};
+
+use aoc_utils::{parse_number, read_letters, Answer, Grid, ParseError, Part, Point2, Solution};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Register {
+    pub value: i32,
+}
+
+impl Default for Register {
+    fn default() -> Self {
+        Self { value: 1 }
+    }
+}
+
+pub trait Instruction
+where
+    Self: Debug,
+{
+    /// Applies the instruction, once its last cycle is over.
+    fn execute(&self, x: &mut Register);
+}
+
+#[derive(Debug, Default)]
+pub struct Noop;
+
+impl FromStr for Noop {
+    type Err = ParseError;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        match input {
+            "" => Ok(Self),
+            _ => Err(ParseError::new(input, "no argument")),
+        }
+    }
+}
+
+impl Instruction for Noop {
+    fn execute(&self, _x: &mut Register) {}
+}
+
+#[derive(Debug, Default)]
+pub struct AddX {
+    pub value: i32,
+}
+
+impl FromStr for AddX {
+    type Err = ParseError;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        Ok(Self {
+            value: parse_number(input, input)?,
+        })
+    }
+}
+
+impl Instruction for AddX {
+    fn execute(&self, x: &mut Register) {
+        x.value += self.value;
+    }
+}
+
+type Decoder = fn(&str) -> Result<Rc<dyn Instruction>, ParseError>;
+
+/// The instructions a program can use, by name, with how many cycles each takes.
+#[derive(Clone)]
+pub struct InstructionSet {
+    instructions: Vec<(&'static str, usize, Decoder)>,
+}
+
+impl Default for InstructionSet {
+    /// `noop` and `addx <value>`.
+    fn default() -> Self {
+        Self::new()
+            .register::<Noop>("noop", 1)
+            .register::<AddX>("addx", 2)
+    }
+}
+
+impl InstructionSet {
+    pub fn new() -> Self {
+        Self {
+            instructions: vec![],
+        }
+    }
+
+    /// Lets programs use `name`, parsing what follows it as an `I` that takes
+    /// `cycles` cycles.
+    ///
+    /// Panics if `cycles` is 0, as every instruction takes at least one.
+    pub fn register<I>(mut self, name: &'static str, cycles: usize) -> Self
+    where
+        I: Instruction + FromStr<Err = ParseError> + 'static,
+    {
+        assert!(cycles > 0, "{name} must take at least one cycle");
+        self.instructions.retain(|&(known, ..)| known != name);
+        self.instructions
+            .push((name, cycles, |args| Ok(Rc::new(args.parse::<I>()?))));
+        self
+    }
+
+    pub fn parse(&self, input: &str) -> Result<Program, ParseError> {
+        let lines = input
+            .lines()
+            .map(|line| {
+                let (name, args) = line.split_once(' ').unwrap_or((line, &line[line.len()..]));
+                let &(name, cycles, decode) = self
+                    .instructions
+                    .iter()
+                    .find(|&&(known, ..)| known == name)
+                    .ok_or_else(|| {
+                        let names: Vec<_> =
+                            self.instructions.iter().map(|&(name, ..)| name).collect();
+                        ParseError::new(name, names.join(" or ")).within(input, name)
+                    })?;
+                Ok(ProgramLine {
+                    name,
+                    cycles,
+                    instruction: decode(args).map_err(|err| err.within(input, args))?,
+                })
+            })
+            .collect::<Result<_, ParseError>>()?;
+        Ok(Program { lines })
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct ProgramLine {
+    pub name: &'static str,
+    pub cycles: usize,
+    pub instruction: Rc<dyn Instruction>,
+}
+
+#[derive(Clone, Debug)]
+pub struct Program {
+    pub lines: Vec<ProgramLine>,
+}
+
+impl FromStr for Program {
+    type Err = ParseError;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        InstructionSet::default().parse(input)
+    }
+}
+
+/// The CPU during one of its cycles.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cycle {
+    /// From 1.
+    pub number: usize,
+    /// X during the cycle, before the instruction ending with it applies.
+    pub x: i32,
+    /// The index of the instruction being executed.
+    pub instruction: usize,
+    /// Whether it's the first cycle of the instruction.
+    pub starts_instruction: bool,
+}
+
+impl Cycle {
+    pub fn signal_strength(&self) -> i32 {
+        self.x * self.number as i32
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Breakpoint {
+    /// Stops during the cycle with this number.
+    Cycle(usize),
+    /// Stops during the first cycle of the instruction at this index.
+    Instruction(usize),
+}
+
+impl Breakpoint {
+    fn is_hit(self, cycle: &Cycle) -> bool {
+        match self {
+            Breakpoint::Cycle(number) => cycle.number == number,
+            Breakpoint::Instruction(index) => {
+                cycle.instruction == index && cycle.starts_instruction
+            }
+        }
+    }
+}
+
+/// Why the CPU stopped running.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Stop {
+    /// The program is over.
+    Halted,
+    Breakpoint(Breakpoint),
+    /// The predicate given to [`CPU::run_until`] held.
+    Predicate,
+}
+
+type Observer<'a> = Box<dyn FnMut(&Cycle) + 'a>;
+
+pub struct CPU<'a> {
+    pub x: Register,
+    program: Program,
+    /// The cycle in progress, or the last one.
+    cycle: Option<Cycle>,
+    /// Whether `cycle` still has to end.
+    in_cycle: bool,
+    /// The index of the instruction to execute.
+    pointer: usize,
+    /// The cycles the instruction at `pointer` still takes, 0 before it starts.
+    cycles_left: usize,
+    breakpoints: Vec<Breakpoint>,
+    observers: Vec<Observer<'a>>,
+    trace: Option<Vec<String>>,
+}
+
+impl<'a> CPU<'a> {
+    pub fn new(program: Program) -> Self {
+        Self {
+            x: Register::default(),
+            program,
+            cycle: None,
+            in_cycle: false,
+            pointer: 0,
+            cycles_left: 0,
+            breakpoints: vec![],
+            observers: vec![],
+            trace: None,
+        }
+    }
+
+    /// The cycle in progress, or the last one.
+    pub fn cycle(&self) -> Option<Cycle> {
+        self.cycle
+    }
+
+    pub fn add_breakpoint(&mut self, breakpoint: Breakpoint) -> &mut Self {
+        self.breakpoints.push(breakpoint);
+        self
+    }
+
+    /// Calls `observer` during every cycle from now on.
+    pub fn observe(&mut self, observer: impl FnMut(&Cycle) + 'a) -> &mut Self {
+        self.observers.push(Box::new(observer));
+        self
+    }
+
+    /// Logs every cycle from now on, see [`CPU::trace`].
+    pub fn start_tracing(&mut self) -> &mut Self {
+        self.trace.get_or_insert_with(Vec::new);
+        self
+    }
+
+    /// A line per cycle since [`CPU::start_tracing`].
+    pub fn trace(&self) -> &[String] {
+        self.trace.as_deref().unwrap_or_default()
+    }
+
+    /// Runs cycles until one for which `predicate` holds, or hitting a
+    /// breakpoint, stopping during that cycle, or until the program is over.
+    pub fn run_until(&mut self, mut predicate: impl FnMut(&Cycle) -> bool) -> Stop {
+        loop {
+            if self.in_cycle {
+                self.end_cycle();
+            }
+            let Some(cycle) = self.start_cycle() else {
+                return Stop::Halted;
+            };
+            for observer in &mut self.observers {
+                observer(&cycle);
+            }
+            if let Some(&breakpoint) = self.breakpoints.iter().find(|bp| bp.is_hit(&cycle)) {
+                return Stop::Breakpoint(breakpoint);
+            }
+            if predicate(&cycle) {
+                return Stop::Predicate;
+            }
+        }
+    }
+
+    pub fn run(&mut self) -> Stop {
+        self.run_until(|_| false)
+    }
+
+    /// Runs the next cycle, stopping during it.
+    pub fn step(&mut self) -> Option<Cycle> {
+        match self.run_until(|_| true) {
+            Stop::Halted => None,
+            _ => self.cycle,
+        }
+    }
+
+    fn start_cycle(&mut self) -> Option<Cycle> {
+        let line = self.program.lines.get(self.pointer)?;
+        let starts_instruction = self.cycles_left == 0;
+        if starts_instruction {
+            self.cycles_left = line.cycles;
+        }
+        let cycle = Cycle {
+            number: self.cycle.map_or(1, |cycle| cycle.number + 1),
+            x: self.x.value,
+            instruction: self.pointer,
+            starts_instruction,
+        };
+        if let Some(trace) = &mut self.trace {
+            trace.push(format!(
+                "cycle {}: x={}, #{} {:?}",
+                cycle.number, cycle.x, cycle.instruction, line.instruction
+            ));
+        }
+        self.cycle = Some(cycle);
+        self.in_cycle = true;
+        Some(cycle)
+    }
+
+    fn end_cycle(&mut self) {
+        self.in_cycle = false;
+        self.cycles_left -= 1;
+        if self.cycles_left == 0 {
+            self.program.lines[self.pointer]
+                .instruction
+                .execute(&mut self.x);
+            self.pointer += 1;
+        }
+    }
+}
+
+/// The signal strengths during the 20th cycle and every 40 cycles after, up to
+/// the 220th.
+pub fn sum_of_signal_strengths(program: Program) -> i32 {
+    let mut sum = 0;
+    let mut cpu = CPU::new(program);
+    cpu.observe(|cycle| {
+        if cycle.number % 40 == 20 {
+            sum += cycle.signal_strength();
+        }
+    });
+    cpu.run_until(|cycle| cycle.number == 220);
+    drop(cpu);
+    sum
+}
+
+/// The screen the CPU draws on, a pixel per cycle, row by row.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Crt {
+    pub pixels: Grid<bool>,
+}
+
+impl Default for Crt {
+    fn default() -> Self {
+        Self {
+            pixels: Grid::filled(CRT_WIDTH, CRT_HEIGHT, false),
+        }
+    }
+}
+
+pub const CRT_WIDTH: usize = 40;
+pub const CRT_HEIGHT: usize = 6;
+
+impl Crt {
+    /// The screen once `program` has drawn every pixel.
+    pub fn run(program: Program) -> Self {
+        let mut crt = Self::default();
+        let mut cpu = CPU::new(program);
+        cpu.observe(|cycle| crt.draw(cycle));
+        cpu.run_until(|cycle| cycle.number == CRT_WIDTH * CRT_HEIGHT);
+        drop(cpu);
+        crt
+    }
+
+    /// Draws the pixel of `cycle`, lit if the sprite, 3 pixels wide around X,
+    /// covers it.
+    pub fn draw(&mut self, cycle: &Cycle) {
+        let i = cycle.number - 1;
+        let pos = Point2::new(i % CRT_WIDTH, i / CRT_WIDTH);
+        if let Some(pixel) = self.pixels.get_mut(pos) {
+            *pixel = (cycle.x - pos.x as i32).abs() <= 1;
+        }
+    }
+
+    /// The letters on the screen, if it shows any.
+    pub fn read(&self) -> Option<String> {
+        read_letters(&self.pixels)
+    }
+
+    /// The screen as a plain PBM image, each row's bits packed on a line of its
+    /// own, within the format's 70 characters.
+    pub fn to_pbm(&self) -> String {
+        let rows: Vec<String> = self
+            .pixels
+            .rows()
+            .map(|row| row.iter().map(|&lit| if lit { '1' } else { '0' }).collect())
+            .collect();
+        format!(
+            "P1\n{} {}\n{}\n",
+            self.pixels.width(),
+            self.pixels.height(),
+            rows.join("\n")
+        )
+    }
+}
+
+/// Lit pixels as `#`, the others as `.`.
+impl Display for Crt {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let pixels = self.pixels.map(|&lit| if lit { '#' } else { '.' });
+        write!(f, "{pixels}")
+    }
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    type Input = Program;
+
+    fn parse(input: &str) -> anyhow::Result<Self::Input> {
+        Ok(input.parse()?)
+    }
+
+    fn part1(program: &Self::Input) -> anyhow::Result<Answer> {
+        Ok(sum_of_signal_strengths(program.clone()).into())
+    }
+
+    fn part2(program: &Self::Input) -> anyhow::Result<Answer> {
+        let crt = Crt::run(program.clone());
+        Ok(crt.read().unwrap_or_else(|| crt.to_string()).into())
+    }
+
+    fn render(program: &Self::Input, part: Part) -> anyhow::Result<Option<String>> {
+        if part == Part::One {
+            return Ok(None);
+        }
+        Ok(Some(Crt::run(program.clone()).to_string()))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use aoc_utils::ParseError;
+
+    use super::{
+        sum_of_signal_strengths, Breakpoint, Crt, Instruction, InstructionSet, Program, Register,
+        Stop, CPU,
+    };
+
+    #[test]
+    fn part1_1() {
+        let program: Program = include_str!("example1_1").parse().unwrap();
+        let mut cpu = CPU::new(program);
+
+        let xs: Vec<i32> = std::iter::from_fn(|| cpu.step())
+            .map(|cycle| cycle.x)
+            .collect();
+        assert_eq!(xs, vec![1, 1, 1, 4, 4]);
+        assert_eq!(cpu.x.value, -1);
+        assert_eq!(cpu.cycle().unwrap().number, 5);
+        assert_eq!(cpu.run(), Stop::Halted);
+    }
+
+    #[test]
+    fn part1_2() {
+        let input = include_str!("example1_2");
+        let mut cpu = CPU::new(input.parse().unwrap());
+        for number in [20, 60, 100, 140, 180, 220] {
+            cpu.add_breakpoint(Breakpoint::Cycle(number));
+        }
+
+        let mut strengths = vec![];
+        while let Stop::Breakpoint(_) = cpu.run() {
+            strengths.push(cpu.cycle().unwrap().signal_strength());
+        }
+        assert_eq!(strengths, vec![420, 1140, 1800, 2940, 2880, 3960]);
+        assert_eq!(strengths.iter().sum::<i32>(), 13140);
+
+        assert_eq!(sum_of_signal_strengths(input.parse().unwrap()), 13140);
+    }
+
+    #[test]
+    fn part2() {
+        let crt = Crt::run(include_str!("example1_2").parse().unwrap());
+        assert_eq!(crt.to_string(), include_str!("expected_part2"));
+        assert_eq!(crt.read(), None);
+
+        let pbm = crt.to_pbm();
+        assert!(pbm.starts_with("P1\n40 6\n11001100"));
+        assert_eq!(pbm.lines().count(), 2 + 6);
+        assert!(pbm.lines().all(|line| line.len() <= 70));
+    }
+
+    #[test]
+    fn breakpoints_and_trace() {
+        let mut cpu = CPU::new(include_str!("example1_1").parse().unwrap());
+        cpu.start_tracing()
+            .add_breakpoint(Breakpoint::Instruction(2));
+
+        assert_eq!(cpu.run(), Stop::Breakpoint(Breakpoint::Instruction(2)));
+        let cycle = cpu.cycle().unwrap();
+        assert_eq!((cycle.number, cycle.x), (4, 4));
+        assert_eq!(
+            cpu.trace(),
+            [
+                "cycle 1: x=1, #0 Noop",
+                "cycle 2: x=1, #1 AddX { value: 3 }",
+                "cycle 3: x=1, #1 AddX { value: 3 }",
+                "cycle 4: x=4, #2 AddX { value: -5 }",
+            ]
+        );
+
+        assert_eq!(cpu.run_until(|cycle| cycle.x < 0), Stop::Halted);
+        assert_eq!(cpu.trace().len(), 5);
+    }
+
+    /// Multiplies X, taking longer than adding.
+    #[derive(Debug)]
+    struct MulX(i32);
+
+    impl std::str::FromStr for MulX {
+        type Err = ParseError;
+
+        fn from_str(input: &str) -> Result<Self, Self::Err> {
+            Ok(Self(aoc_utils::parse_number(input, input)?))
+        }
+    }
+
+    impl Instruction for MulX {
+        fn execute(&self, x: &mut Register) {
+            x.value *= self.0;
+        }
+    }
+
+    #[test]
+    fn custom_instructions() {
+        let instructions = InstructionSet::default().register::<MulX>("mulx", 3);
+        let program = instructions.parse("addx 2\nmulx 5\nnoop\n").unwrap();
+        assert_eq!(program.lines[1].cycles, 3);
+
+        let mut xs = vec![];
+        let mut cpu = CPU::new(program);
+        cpu.observe(|cycle| xs.push(cycle.x));
+        cpu.run();
+        drop(cpu);
+        assert_eq!(xs, vec![1, 1, 3, 3, 3, 15]);
+    }
+
+    #[test]
+    #[should_panic(expected = "mulx must take at least one cycle")]
+    fn instructions_take_cycles() {
+        let _ = InstructionSet::default().register::<MulX>("mulx", 0);
+    }
+
+    #[test]
+    fn bad_program() {
+        let err = "noop\naddx 3\nsubx 2\n".parse::<Program>().unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 3, column 1: expected noop or addx, found \"subx\""
+        );
+
+        let err = "noop\naddx +-3\n".parse::<Program>().unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 2, column 6: expected a number, found \"+-3\""
+        );
+
+        let err = "noop\naddx\n".parse::<Program>().unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 2, column 5: expected a number, found nothing"
+        );
+    }
+}
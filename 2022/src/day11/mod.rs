@@ -0,0 +1,290 @@
+use std::{
+    fmt::Debug,
+    str::{FromStr, Lines},
+};
+
+use aoc_utils::{parse_number, parse_within, Answer, ParseError, Part, Solution};
+
+#[derive(Debug, Clone)]
+pub struct Item {
+    pub value: u64,
+}
+
+impl Item {
+    pub fn inspect(&mut self, monkey: &Monkey, worry_levels_divider: u64) -> usize {
+        self.value = monkey.operation.run(&self.value);
+        self.value /= worry_levels_divider;
+        if self.value.is_multiple_of(monkey.test_divisible_by) {
+            monkey.if_true_throw_to
+        } else {
+            monkey.if_false_throw_to
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub enum OperationType {
+    Add,
+    Mul,
+}
+
+#[derive(Debug, Clone)]
+pub struct Operation {
+    pub value: Option<u64>,
+    pub operation_type: OperationType,
+}
+
+impl Operation {
+    fn run(&self, old: &u64) -> u64 {
+        match self.operation_type {
+            OperationType::Add => old + self.value.unwrap_or(*old),
+            OperationType::Mul => old * self.value.unwrap_or(*old),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Monkey {
+    pub items: Vec<Item>,
+    pub activity: usize,
+    pub operation: Operation,
+    pub test_divisible_by: u64,
+    pub if_true_throw_to: usize,
+    pub if_false_throw_to: usize,
+}
+
+impl FromStr for Monkey {
+    type Err = ParseError;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let mut lines = input.lines();
+        field(input, &mut lines, "Monkey ")?;
+
+        let items = field(input, &mut lines, "Starting items: ")?;
+        let items = match items {
+            "" => vec![],
+            _ => items
+                .split(", ")
+                .map(|value| {
+                    Ok(Item {
+                        value: parse_number(input, value)?,
+                    })
+                })
+                .collect::<Result<_, ParseError>>()?,
+        };
+
+        let operation = field(input, &mut lines, "Operation: new = old ")?;
+        let operation =
+            match operation.split_once(' ') {
+                Some((operation_type, value)) => {
+                    let operation_type = match operation_type {
+                        "+" => OperationType::Add,
+                        "*" => OperationType::Mul,
+                        _ => {
+                            return Err(ParseError::new(operation_type, "+ or *")
+                                .within(input, operation_type))
+                        }
+                    };
+                    let value = match value {
+                        "old" => None,
+                        _ => Some(parse_number(input, value)?),
+                    };
+                    Operation {
+                        value,
+                        operation_type,
+                    }
+                }
+                None => {
+                    return Err(ParseError::new(operation, "an operator and a value")
+                        .within(input, operation))
+                }
+            };
+
+        let test_divisible_by = field(input, &mut lines, "Test: divisible by ")?;
+        let if_true_throw_to = field(input, &mut lines, "If true: throw to monkey ")?;
+        let if_false_throw_to = field(input, &mut lines, "If false: throw to monkey ")?;
+
+        Ok(Self {
+            items,
+            activity: 0,
+            operation,
+            test_divisible_by: parse_number(input, test_divisible_by)?,
+            if_true_throw_to: parse_number(input, if_true_throw_to)?,
+            if_false_throw_to: parse_number(input, if_false_throw_to)?,
+        })
+    }
+}
+
+/// What follows `prefix` on the next line of `input`, a monkey's notes.
+fn field<'a>(input: &'a str, lines: &mut Lines<'a>, prefix: &str) -> Result<&'a str, ParseError> {
+    let line = lines.next().unwrap_or(&input[input.len()..]).trim_start();
+    line.strip_prefix(prefix).ok_or_else(|| {
+        ParseError::new(line, format!("{:?}", prefix.trim_end())).within(input, line)
+    })
+}
+
+#[derive(Debug, Clone)]
+pub struct Monkeys {
+    pub monkeys: Vec<Monkey>,
+}
+
+impl FromStr for Monkeys {
+    type Err = ParseError;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let notes: Vec<&str> = input.trim_end().split("\n\n").collect();
+        let monkeys: Vec<Monkey> = notes
+            .iter()
+            .map(|monkey| parse_within(input, monkey))
+            .collect::<Result<_, ParseError>>()?;
+
+        for (i, (notes, monkey)) in notes.iter().zip(&monkeys).enumerate() {
+            let targets = notes.lines().filter_map(|line| {
+                let line = line.trim_start();
+                line.strip_prefix("If true: throw to monkey ")
+                    .or_else(|| line.strip_prefix("If false: throw to monkey "))
+            });
+            let throws = [monkey.if_true_throw_to, monkey.if_false_throw_to];
+            if let Some((target, _)) = targets
+                .zip(throws)
+                .find(|&(_, to)| to >= monkeys.len() || to == i)
+            {
+                return Err(ParseError::new(
+                    target,
+                    format!("another monkey than {i}, from 0 to {}", monkeys.len() - 1),
+                )
+                .within(input, target));
+            }
+        }
+
+        Ok(Self { monkeys })
+    }
+}
+
+impl Monkeys {
+    pub fn round(&mut self, worry_levels_divider: u64) {
+        let mut multipliers: Vec<u64> = self
+            .monkeys
+            .iter()
+            .map(|monkey| monkey.test_divisible_by)
+            .collect();
+        multipliers.dedup();
+        let ppcm = multipliers.iter().product::<u64>();
+        for i in 0..self.monkeys.len() {
+            let items = std::mem::take(&mut self.monkeys[i].items);
+            self.monkeys[i].activity += items.len();
+            for mut item in items {
+                let target_monkey = item.inspect(&self.monkeys[i], worry_levels_divider);
+                item.value %= ppcm;
+                self.monkeys[target_monkey].items.push(item);
+            }
+        }
+    }
+
+    pub fn business(&self) -> usize {
+        let mut activities: Vec<usize> =
+            self.monkeys.iter().map(|monkey| monkey.activity).collect();
+        activities.sort();
+        let activities: Vec<&usize> = activities.iter().rev().take(2).collect();
+        *activities.first().unwrap() * *activities.get(1).unwrap()
+    }
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    type Input = Monkeys;
+
+    fn parse(input: &str) -> anyhow::Result<Self::Input> {
+        Ok(input.parse()?)
+    }
+
+    fn part1(monkeys: &Self::Input) -> anyhow::Result<Answer> {
+        let mut monkeys = monkeys.clone();
+        (0..20).for_each(|_| {
+            monkeys.round(3);
+        });
+        Ok(monkeys.business().into())
+    }
+
+    fn part2(monkeys: &Self::Input) -> anyhow::Result<Answer> {
+        let mut monkeys = monkeys.clone();
+        (0..10000).for_each(|_| {
+            monkeys.round(1);
+        });
+        Ok(monkeys.business().into())
+    }
+
+    fn render(monkeys: &Self::Input, part: Part) -> anyhow::Result<Option<String>> {
+        if part == Part::Two {
+            return Ok(None);
+        }
+        let mut monkeys = monkeys.clone();
+        (0..20).for_each(|_| {
+            monkeys.round(3);
+        });
+        Ok(Some(format!("{:#?}", monkeys)))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::Monkeys;
+
+    #[test]
+    fn test_part1() {
+        let input = include_str!("ex_part1");
+        let mut monkeys: Monkeys = input.parse().unwrap();
+        (0..20).for_each(|_| {
+            monkeys.round(3);
+        });
+        dbg!(&monkeys);
+
+        assert_eq!(monkeys.business(), 10605);
+    }
+
+    #[test]
+    fn test_part2() {
+        let input = include_str!("ex_part1");
+        let mut monkeys: Monkeys = input.parse().unwrap();
+        (0..10000).for_each(|_| {
+            monkeys.round(1);
+        });
+
+        assert_eq!(monkeys.business(), 2713310158);
+    }
+
+    #[test]
+    fn bad_monkeys() {
+        let input = include_str!("ex_part1").replacen("new = old * 19", "new = old / 19", 1);
+        let err = input.parse::<Monkeys>().unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 3, column 24: expected + or *, found \"/\""
+        );
+
+        let input = include_str!("ex_part1").replacen("  Test: divisible by 23\n", "", 1);
+        let err = input.parse::<Monkeys>().unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 4, column 5: expected \"Test: divisible by\", found \"If true: throw to monkey 2\""
+        );
+    }
+
+    #[test]
+    fn bad_throws() {
+        let input = include_str!("ex_part1").replacen("throw to monkey 3", "throw to monkey 4", 1);
+        let err = input.parse::<Monkeys>().unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 6, column 31: expected another monkey than 0, from 0 to 3, found \"4\""
+        );
+
+        let input = include_str!("ex_part1").replacen("throw to monkey 0", "throw to monkey 1", 1);
+        let err = input.parse::<Monkeys>().unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 13, column 31: expected another monkey than 1, from 0 to 3, found \"1\""
+        );
+    }
+}
@@ -0,0 +1,197 @@
+use std::str::FromStr;
+
+use anyhow::Context;
+use aoc_utils::{bfs, multi_bfs, Answer, Grid, ParseError, Part, Paths, Pos, Solution};
+
+/// A square to start hiking from, and how many steps it takes from there to E.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Hike {
+    pub start: Pos,
+    pub steps: usize,
+}
+
+#[derive(Clone, Debug)]
+pub struct Map {
+    pub start: Pos,
+    pub end: Pos,
+    /// From 0 for `a` to 25 for `z`.
+    pub elevations: Grid<u8>,
+}
+
+impl Map {
+    /// The squares one step from `pos` that aren't more than one higher.
+    pub fn squares_around(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        let elevation = self.elevations[pos];
+        self.elevations
+            .neighbours(pos)
+            .filter(move |&next| self.elevations[next] <= elevation + 1)
+    }
+
+    /// The squares one step from `pos` that can step up to it.
+    pub fn squares_below(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        let elevation = self.elevations[pos];
+        self.elevations
+            .neighbours(pos)
+            .filter(move |&previous| elevation <= self.elevations[previous] + 1)
+    }
+
+    /// How far every square reachable from `starts` is from the closest of them.
+    pub fn climb(&self, starts: impl IntoIterator<Item = Pos>) -> Paths<Pos> {
+        multi_bfs(starts, |&pos| self.squares_around(pos))
+    }
+
+    /// How far E is from every square it can be reached from.
+    pub fn descend(&self) -> Paths<Pos> {
+        bfs(self.end, |&pos| self.squares_below(pos))
+    }
+
+    pub fn fewest_steps(&self) -> Option<usize> {
+        bfs(self.start, |&pos| self.squares_around(pos)).distance(&self.end)
+    }
+
+    /// The square of elevation `a` closest to E, the first one row by row when
+    /// several are as close.
+    pub fn best_hike(&self) -> Option<Hike> {
+        let descent = self.descend();
+        self.elevations
+            .iter()
+            .filter(|&(_, &elevation)| elevation == 0)
+            .filter_map(|(start, _)| {
+                Some(Hike {
+                    start,
+                    steps: descent.distance(&start)?,
+                })
+            })
+            .min_by_key(|hike| hike.steps)
+    }
+
+    /// The grid with the squares `paths` reached marked with `#`.
+    pub fn render(&self, paths: &Paths<Pos>) -> String {
+        self.elevations
+            .rows()
+            .enumerate()
+            .map(|(y, row)| {
+                let row: String = row
+                    .iter()
+                    .enumerate()
+                    .map(|(x, &elevation)| {
+                        let pos = Pos::new(x, y);
+                        let elevation = (elevation + b'a') as char;
+                        if pos == self.start {
+                            "S  ".to_string()
+                        } else if pos == self.end {
+                            "E  ".to_string()
+                        } else if paths.contains(&pos) {
+                            format!("#{} ", elevation)
+                        } else {
+                            format!(" {} ", elevation)
+                        }
+                    })
+                    .collect();
+                format!("{:>2} {}", y, row)
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+impl FromStr for Map {
+    type Err = ParseError;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let marks = Grid::from_chars(input, "an elevation, from a to z, S or E", |c| {
+            matches!(c, 'a'..='z' | 'S' | 'E').then_some(c)
+        })?;
+        let find = |mark: char| {
+            marks.position(|&c| c == mark).ok_or_else(|| {
+                let end = &input[input.len()..];
+                ParseError::new(end, format!("a square marked {mark}")).within(input, end)
+            })
+        };
+
+        Ok(Self {
+            start: find('S')?,
+            end: find('E')?,
+            elevations: marks.map(|&c| match c {
+                'S' => 0,
+                'E' => 25,
+                c => c as u8 - b'a',
+            }),
+        })
+    }
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    type Input = Map;
+
+    fn parse(input: &str) -> anyhow::Result<Self::Input> {
+        Ok(input.parse()?)
+    }
+
+    fn part1(map: &Self::Input) -> anyhow::Result<Answer> {
+        Ok(map.fewest_steps().context("E can't be reached")?.into())
+    }
+
+    fn part2(map: &Self::Input) -> anyhow::Result<Answer> {
+        let hike = map.best_hike().context("E can't be reached from any a")?;
+        Ok(hike.steps.into())
+    }
+
+    fn render(map: &Self::Input, part: Part) -> anyhow::Result<Option<String>> {
+        if part == Part::Two {
+            return Ok(None);
+        }
+        Ok(Some(map.render(&map.climb([map.start]))))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use aoc_utils::Pos;
+
+    use super::{Hike, Map};
+
+    #[test]
+    fn example_part1() {
+        let input = include_str!("example_part1");
+        let example_map: Map = input.parse().unwrap();
+        assert_eq!(example_map.fewest_steps(), Some(31));
+    }
+
+    #[test]
+    fn example_part2() {
+        let input = include_str!("example_part1");
+        let example_map: Map = input.parse().unwrap();
+        assert_eq!(
+            example_map.best_hike(),
+            Some(Hike {
+                start: Pos::new(0, 4),
+                steps: 29
+            })
+        );
+
+        // The closest a needn't be in the first column.
+        let map: Map = format!("Saaaabcdefghijklmnopqrstuvwxyz\n{}E\n", "z".repeat(29))
+            .parse()
+            .unwrap();
+        assert_eq!(map.fewest_steps(), Some(30));
+        assert_eq!(
+            map.best_hike(),
+            Some(Hike {
+                start: Pos::new(4, 0),
+                steps: 26
+            })
+        );
+    }
+
+    #[test]
+    fn bad_map() {
+        let err = "Sabqponm\nabcry?xl\n".parse::<Map>().unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 2, column 6: expected an elevation, from a to z, S or E, found \"?\""
+        );
+    }
+}
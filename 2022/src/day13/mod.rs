@@ -0,0 +1,301 @@
+use std::{cmp::Ordering, str::FromStr};
+
+use anyhow::Context;
+use aoc_utils::{parse_within, Answer, ParseError, Part, Solution};
+use serde_json::json;
+
+#[derive(PartialEq, Debug)]
+pub enum Token {
+    Number(usize),
+    ParenOpen,
+    ParenClose,
+}
+
+#[derive(PartialEq, Eq, Default, Debug)]
+pub struct Line {
+    pub input: String,
+}
+
+impl Clone for Line {
+    fn clone(&self) -> Self {
+        Self {
+            input: self.input.clone(),
+        }
+    }
+}
+
+impl PartialOrd for Line {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Line {
+    fn cmp(&self, other: &Self) -> Ordering {
+        let couple_of_lines = CoupleOfLines {
+            line1: self.clone(),
+            line2: other.clone(),
+        };
+
+        if couple_of_lines.compare() {
+            return Ordering::Less;
+        }
+        Ordering::Greater
+    }
+}
+
+impl FromStr for Line {
+    type Err = ParseError;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let expected = "a packet, a list of numbers and lists";
+        match serde_json::from_str(input) {
+            Ok(value @ serde_json::Value::Array(_)) if is_packet(&value) => Ok(Self {
+                input: input.to_string(),
+            }),
+            Ok(_) => Err(ParseError::new(input, expected)),
+            Err(err) => {
+                let found = input.get(err.column().saturating_sub(1)..).unwrap_or(input);
+                Err(ParseError::new(found, expected).within(input, found))
+            }
+        }
+    }
+}
+
+fn is_packet(value: &serde_json::Value) -> bool {
+    match value {
+        serde_json::Value::Number(n) => n.is_u64(),
+        serde_json::Value::Array(values) => values.iter().all(is_packet),
+        _ => false,
+    }
+}
+
+pub struct CoupleOfLines {
+    pub line1: Line,
+    pub line2: Line,
+}
+
+pub fn compare_values(left: &serde_json::Value, right: &serde_json::Value) -> Option<bool> {
+    match left {
+        serde_json::Value::Number(i) => match right {
+            serde_json::Value::Number(j) => {
+                // both values are integers
+                if i.as_u64() < j.as_u64() {
+                    return Some(true);
+                }
+                if i.as_u64() > j.as_u64() {
+                    return Some(false);
+                }
+            }
+            serde_json::Value::Array(_) => {
+                return compare_values(&json!([i]), right);
+            }
+            _ => unreachable!(),
+        },
+        serde_json::Value::Array(arr1) => match right {
+            serde_json::Value::Array(arr2) => {
+                let x: Vec<Option<bool>> = arr1
+                    .iter()
+                    .zip(arr2)
+                    .map(|values| compare_values(values.0, values.1))
+                    .filter(|result| result.is_some())
+                    .take(1)
+                    .collect();
+                if let Some(result) = x.first() {
+                    return *result;
+                } else {
+                    match PartialOrd::partial_cmp(&arr1.len(), &arr2.len()) {
+                        Some(Ordering::Less) => return Some(true),
+                        Some(Ordering::Greater) => return Some(false),
+                        Some(Ordering::Equal) => return None,
+                        None => unreachable!(),
+                    };
+                    // return None;
+                }
+            }
+            serde_json::Value::Number(n) => {
+                return compare_values(left, &json!([n]));
+            }
+            _ => unreachable!(),
+        },
+        _ => unreachable!(),
+    }
+    None
+}
+
+impl CoupleOfLines {
+    pub fn compare(&self) -> bool {
+        let line1: serde_json::Value =
+            serde_json::from_str(&self.line1.input).expect("packets are checked when parsed");
+        let line2: serde_json::Value =
+            serde_json::from_str(&self.line2.input).expect("packets are checked when parsed");
+
+        let result = compare_values(&line1, &line2);
+        if let Some(result) = result {
+            return result;
+        }
+        true
+    }
+}
+
+pub fn sum_of_right_order_indices(input: &str) -> Result<usize, ParseError> {
+    let lines: Vec<&str> = input.lines().collect();
+    let mut sum = 0;
+    for (i, group) in lines.chunks(3).enumerate() {
+        let end = &input[input.len()..];
+        let line2 = group.get(1).copied().unwrap_or(end);
+        let couple_of_lines = CoupleOfLines {
+            line1: parse_within(input, group[0])?,
+            line2: parse_within(input, line2)?,
+        };
+
+        if couple_of_lines.compare() {
+            sum += i + 1;
+        }
+    }
+    Ok(sum)
+}
+
+pub fn sorted_packets(input: &str) -> Result<Vec<Line>, ParseError> {
+    let mut lines: Vec<Line> = input
+        .lines()
+        .filter(|line| !line.is_empty())
+        .map(|line| parse_within(input, line))
+        .collect::<Result<_, _>>()?;
+    for divider in ["[[2]]", "[[6]]"] {
+        lines.push(Line {
+            input: divider.to_string(),
+        });
+    }
+    lines.sort();
+    Ok(lines)
+}
+
+pub fn decoder_key(lines: &[Line]) -> anyhow::Result<usize> {
+    let mut key = 1;
+    for divider in ["[[2]]", "[[6]]"] {
+        key *= lines
+            .iter()
+            .position(|line| line.input == divider)
+            .context("divider packet not found")?
+            + 1;
+    }
+    Ok(key)
+}
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    type Input = String;
+
+    fn parse(input: &str) -> anyhow::Result<Self::Input> {
+        Ok(input.to_string())
+    }
+
+    fn part1(input: &Self::Input) -> anyhow::Result<Answer> {
+        Ok(sum_of_right_order_indices(input)?.into())
+    }
+
+    fn part2(input: &Self::Input) -> anyhow::Result<Answer> {
+        Ok(decoder_key(&sorted_packets(input)?)?.into())
+    }
+
+    fn render(input: &Self::Input, part: Part) -> anyhow::Result<Option<String>> {
+        match part {
+            Part::One => Ok(None),
+            Part::Two => Ok(Some(format!("{:#?}", sorted_packets(input)?))),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{sum_of_right_order_indices, CoupleOfLines};
+
+    #[test]
+    fn test_part1_1() {
+        let couple_of_lines = CoupleOfLines {
+            line1: "[1,1,3,1,1]".parse().unwrap(),
+            line2: "[1,1,5,1,1]".parse().unwrap(),
+        };
+        assert!(couple_of_lines.compare());
+    }
+
+    #[test]
+    fn test_part1_2() {
+        let couple_of_lines = CoupleOfLines {
+            line1: "[[1],[2,3,4]]".parse().unwrap(),
+            line2: "[[1],4]".parse().unwrap(),
+        };
+        assert!(couple_of_lines.compare());
+    }
+
+    #[test]
+    fn test_part1_3() {
+        let couple_of_lines = CoupleOfLines {
+            line1: "[9]".parse().unwrap(),
+            line2: "[[8,7,6]]".parse().unwrap(),
+        };
+        assert!(!couple_of_lines.compare());
+    }
+
+    #[test]
+    fn test_part1_4() {
+        let couple_of_lines = CoupleOfLines {
+            line1: "[[4,4],4,4]".parse().unwrap(),
+            line2: "[[4,4],4,4]".parse().unwrap(),
+        };
+        assert!(couple_of_lines.compare());
+    }
+
+    #[test]
+    fn test_part1_5() {
+        let couple_of_lines = CoupleOfLines {
+            line1: "[7,7,7,7]".parse().unwrap(),
+            line2: "[7,7,7]".parse().unwrap(),
+        };
+        assert!(!couple_of_lines.compare());
+    }
+
+    #[test]
+    fn test_part1_6() {
+        let couple_of_lines = CoupleOfLines {
+            line1: "[]".parse().unwrap(),
+            line2: "[3]".parse().unwrap(),
+        };
+        assert!(couple_of_lines.compare());
+    }
+
+    #[test]
+    fn test_part1_7() {
+        let couple_of_lines = CoupleOfLines {
+            line1: "[[[]]]".parse().unwrap(),
+            line2: "[[]]".parse().unwrap(),
+        };
+        assert!(!couple_of_lines.compare());
+    }
+
+    #[test]
+    fn test_part1_8() {
+        let couple_of_lines = CoupleOfLines {
+            line1: "[1,[2,[3,[4,[5,6,7]]]],8,9]".parse().unwrap(),
+            line2: "[1,[2,[3,[4,[5,6,0]]]],8,9]".parse().unwrap(),
+        };
+        assert!(!couple_of_lines.compare());
+    }
+
+    #[test]
+    fn bad_packets() {
+        let err = sum_of_right_order_indices("[1,1]\n[1,2]\n\n[[1],x]\n[3]\n").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 4, column 6: expected a packet, a list of numbers and lists, found \"x]\""
+        );
+
+        let err = sum_of_right_order_indices("[1,1]\n[1,2]\n\n[3]\n").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 5, column 1: expected a packet, a list of numbers and lists, found nothing"
+        );
+    }
+}
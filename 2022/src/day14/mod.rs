@@ -0,0 +1,216 @@
+use std::{ops::RangeInclusive, str::FromStr};
+
+use aoc_utils::{parse_within, Answer, Grid, ParseError, Part, Point2, Solution};
+use itertools::Itertools; // for the `tuple_windows`
+
+#[derive(PartialEq, Debug, Clone)]
+pub enum Square {
+    Block,
+    Sand,
+}
+
+#[derive(Debug, Clone)]
+pub struct Path {
+    pub from: Point2<usize>,
+    pub to: Point2<usize>,
+}
+
+impl From<(Point2<usize>, Point2<usize>)> for Path {
+    fn from((from, to): (Point2<usize>, Point2<usize>)) -> Self {
+        Self { from, to }
+    }
+}
+
+impl Path {
+    pub fn x_iter(&self) -> RangeInclusive<usize> {
+        if self.from.x < self.to.x {
+            self.from.x..=self.to.x
+        } else {
+            self.to.x..=self.from.x
+        }
+    }
+
+    pub fn y_iter(&self) -> RangeInclusive<usize> {
+        if self.from.y < self.to.y {
+            self.from.y..=self.to.y
+        } else {
+            self.to.y..=self.from.y
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Map {
+    pub grid: Grid<Option<Square>>,
+    /// The x of the first column of `grid`, left of 0 when the floor is deep
+    /// enough for sand to pile up past it.
+    pub min_x: isize,
+    /// The y of the lowest rock.
+    pub max_y: usize,
+}
+
+impl Map {
+    pub fn get_at(&self, x: isize, y: usize) -> Option<Square> {
+        let column = usize::try_from(x - self.min_x).ok()?;
+        self.grid.get(Point2::new(column, y))?.clone()
+    }
+
+    pub fn put(&mut self, x: isize, y: usize, square_type: Square) {
+        self.grid[Point2::new((x - self.min_x) as usize, y)] = Some(square_type);
+    }
+
+    /// Two below the lowest rock.
+    pub fn floor(&self) -> usize {
+        self.max_y + 2
+    }
+
+    pub fn render(&self) -> String {
+        self.grid
+            .map(|square| match square {
+                None => '.',
+                Some(Square::Block) => '#',
+                Some(Square::Sand) => 'o',
+            })
+            .to_string()
+    }
+
+    pub fn count_resting_sand(&mut self) -> usize {
+        let mut i = 0;
+        while self.produce_sand(500, false).is_some() {
+            i += 1;
+        }
+        i
+    }
+
+    pub fn count_resting_sand_with_floor(&mut self) -> usize {
+        let mut i = 0;
+        while self.produce_sand(500, true).is_some() {
+            i += 1;
+        }
+        i
+    }
+
+    /// Pours a unit of sand from `x` and returns where it comes to rest, if it
+    /// does: without a `floor` it falls forever once below the lowest rock.
+    pub fn produce_sand(&mut self, x: isize, floor: bool) -> Option<(isize, usize)> {
+        let (mut x, mut y) = (x, 0);
+        if self.get_at(x, y).is_some() {
+            return None;
+        }
+        loop {
+            if y + 1 == self.floor() {
+                if !floor {
+                    return None;
+                }
+                break;
+            }
+            match [x, x - 1, x + 1]
+                .into_iter()
+                .find(|&x| self.get_at(x, y + 1).is_none())
+            {
+                Some(next_x) => {
+                    x = next_x;
+                    y += 1;
+                }
+                None => break,
+            }
+        }
+        self.put(x, y, Square::Sand);
+        Some((x, y))
+    }
+}
+
+impl FromStr for Map {
+    type Err = ParseError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let paths = value
+            .lines()
+            .map(|line| {
+                line.split("->")
+                    .map(|part| parse_within(value, part.trim()))
+                    .collect::<Result<Vec<Point2<usize>>, _>>()
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        // Sand piles up from 500 at most as far to each side as the floor is deep.
+        let max_y = paths.iter().flatten().map(|c| c.y).max().unwrap_or(0);
+        let floor = max_y + 2;
+        let xs = paths.iter().flatten().map(|c| c.x as isize);
+        let min_x = xs.clone().chain([500 - floor as isize]).min().unwrap() - 1;
+        let max_x = xs.chain([500 + floor as isize]).max().unwrap() + 1;
+
+        let mut map = Self {
+            grid: Grid::filled((max_x - min_x + 1) as usize, floor + 1, None),
+            min_x,
+            max_y,
+        };
+        for coords in paths {
+            coords
+                .into_iter()
+                .tuple_windows::<(_, _)>()
+                .map(Into::<Path>::into)
+                .for_each(|path| {
+                    path.x_iter().for_each(|x| {
+                        path.y_iter()
+                            .for_each(|y| map.put(x as isize, y, Square::Block))
+                    })
+                });
+        }
+        Ok(map)
+    }
+}
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    type Input = Map;
+
+    fn parse(input: &str) -> anyhow::Result<Self::Input> {
+        Ok(input.parse()?)
+    }
+
+    fn part1(map: &Self::Input) -> anyhow::Result<Answer> {
+        Ok(map.clone().count_resting_sand().into())
+    }
+
+    fn part2(map: &Self::Input) -> anyhow::Result<Answer> {
+        Ok(map.clone().count_resting_sand_with_floor().into())
+    }
+
+    fn render(map: &Self::Input, part: Part) -> anyhow::Result<Option<String>> {
+        let mut map = map.clone();
+        match part {
+            Part::One => map.count_resting_sand(),
+            Part::Two => map.count_resting_sand_with_floor(),
+        };
+        Ok(Some(map.render()))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::Map;
+
+    #[test]
+    fn bad_paths() {
+        let err = "498,4 -> 498,6\n503,4 -> 502 -> 494,9\n"
+            .parse::<Map>()
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 2, column 10: expected x,y coordinates, found \"502\""
+        );
+    }
+
+    #[test]
+    fn far_from_the_source() {
+        let map: Map = "0,4 -> 2,4\n".parse().unwrap();
+        assert_eq!(map.clone().count_resting_sand(), 0);
+        assert_eq!(map.clone().count_resting_sand_with_floor(), 6 * 6);
+
+        let map: Map = "498,600 -> 502,600\n".parse().unwrap();
+        assert_eq!(map.clone().count_resting_sand(), 4);
+        assert_eq!(map.min_x, 500 - 602 - 1);
+    }
+}
@@ -0,0 +1,203 @@
+use std::{collections::HashSet, ops::RangeInclusive, str::FromStr};
+
+use anyhow::Context;
+use aoc_utils::{parse_lines, parse_number, Answer, IntervalSet, ParseError, Point2, Solution};
+
+/// `x=<x>, y=<y>`, as sensors and beacons are written.
+pub fn parse_coord(value: &str) -> Result<Point2<isize>, ParseError> {
+    let (x, y) = value
+        .split_once(", ")
+        .ok_or_else(|| ParseError::new(value, "x=<x>, y=<y>"))?;
+    let x = x
+        .strip_prefix("x=")
+        .ok_or_else(|| ParseError::new(x, "x=<x>"))?;
+    let y = y
+        .strip_prefix("y=")
+        .ok_or_else(|| ParseError::new(y, "y=<y>").within(value, y))?;
+    Ok(Point2::new(
+        parse_number(value, x)?,
+        parse_number(value, y)?,
+    ))
+}
+
+#[derive(Debug, Clone)]
+pub struct Sensor {
+    pub coord: Point2<isize>,
+}
+
+#[derive(Debug, Clone)]
+pub struct Beacon {
+    pub coord: Point2<isize>,
+}
+
+/// What the sensors tell about a row.
+#[derive(Debug, Default)]
+pub struct Row {
+    /// The x of the beacons on the row.
+    pub beacons: HashSet<isize>,
+    /// The x closer to a sensor than its closest beacon.
+    pub covered: IntervalSet<isize>,
+}
+
+impl Row {
+    pub fn scan(reports: &[Report], y: isize) -> Self {
+        Self {
+            beacons: reports
+                .iter()
+                .map(|report| report.closest_beacon.coord)
+                .filter(|beacon| beacon.y == y)
+                .map(|beacon| beacon.x)
+                .collect(),
+            covered: reports
+                .iter()
+                .filter_map(|report| report.coverage(y))
+                .collect(),
+        }
+    }
+
+    /// How many positions can't hold a beacon.
+    pub fn count(&self) -> usize {
+        let beacons = self
+            .beacons
+            .iter()
+            .filter(|&&x| self.covered.contains(x))
+            .count();
+        self.covered.len() as usize - beacons
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Report {
+    pub sensor: Sensor,
+    pub closest_beacon: Beacon,
+}
+
+impl FromStr for Report {
+    type Err = ParseError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let rest = value
+            .strip_prefix("Sensor at ")
+            .ok_or_else(|| ParseError::new(value, "\"Sensor at\""))?;
+        let (sensor, beacon) = rest.split_once(": closest beacon is at ").ok_or_else(|| {
+            ParseError::new(rest, "a sensor and its closest beacon").within(value, rest)
+        })?;
+
+        Ok(Self {
+            sensor: Sensor {
+                coord: parse_coord(sensor).map_err(|err| err.within(value, sensor))?,
+            },
+            closest_beacon: Beacon {
+                coord: parse_coord(beacon).map_err(|err| err.within(value, beacon))?,
+            },
+        })
+    }
+}
+
+impl Report {
+    /// The x on row `y` closer to the sensor than its closest beacon.
+    pub fn coverage(&self, y: isize) -> Option<RangeInclusive<isize>> {
+        let sensor = self.sensor.coord;
+        let distance = sensor.manhattan(self.closest_beacon.coord);
+        let reach = distance - (y - sensor.y).abs();
+        (reach >= 0).then(|| sensor.x - reach..=sensor.x + reach)
+    }
+}
+
+pub const ROW: isize = 2000000;
+pub const MAX: isize = 4000000;
+
+pub fn positions_without_beacon(reports: &[Report], row: isize) -> usize {
+    Row::scan(reports, row).count()
+}
+
+/// The tuning frequency of the only position within `0..=max` on both axes that
+/// no sensor covers.
+pub fn tuning_frequency(reports: &[Report], max: isize) -> Option<isize> {
+    let area = IntervalSet::from(0..=max);
+    (0..=max).find_map(|y| {
+        let x = area.difference(&Row::scan(reports, y).covered).min()?;
+        Some(x * 4000000 + y)
+    })
+}
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    type Input = Vec<Report>;
+
+    fn parse(input: &str) -> anyhow::Result<Self::Input> {
+        Ok(parse_lines(input)?)
+    }
+
+    fn part1(reports: &Self::Input) -> anyhow::Result<Answer> {
+        Ok(positions_without_beacon(reports, ROW).into())
+    }
+
+    fn part2(reports: &Self::Input) -> anyhow::Result<Answer> {
+        Ok(tuning_frequency(reports, MAX)
+            .context("no room left for the distress beacon")?
+            .into())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::collections::HashSet;
+
+    use aoc_utils::parse_lines;
+
+    use super::{positions_without_beacon, tuning_frequency, Report, Row};
+
+    #[test]
+    fn test_report() {
+        let report: Report = "Sensor at x=2, y=18: closest beacon is at x=-2, y=15"
+            .parse()
+            .unwrap();
+        assert_eq!(report.sensor.coord.x, 2);
+        assert_eq!(report.sensor.coord.y, 18);
+        assert_eq!(report.closest_beacon.coord.x, -2);
+        assert_eq!(report.closest_beacon.coord.y, 15);
+    }
+
+    #[test]
+    fn example1() {
+        let reports: Vec<Report> = parse_lines(include_str!("example")).unwrap();
+
+        let row = Row::scan(&reports, 10);
+        assert_eq!(row.covered.ranges().collect::<Vec<_>>(), vec![-2..=24]);
+        assert_eq!(row.beacons, HashSet::from([2]));
+        assert_eq!(row.count(), 26);
+
+        assert_eq!(positions_without_beacon(&reports, 10), 26);
+    }
+
+    #[test]
+    fn example2() {
+        let reports: Vec<Report> = parse_lines(include_str!("example")).unwrap();
+
+        let row = Row::scan(&reports, 11);
+        assert_eq!(row.covered.gaps().collect::<Vec<_>>(), vec![14..=14]);
+
+        assert_eq!(tuning_frequency(&reports, 20), Some(56000011));
+    }
+
+    #[test]
+    fn bad_reports() {
+        let err = parse_lines::<Report>(
+            "Sensor at x=2, y=18: closest beacon is at x=-2, y=15\n\
+             Sensor at x=9, y=16: closest beacon is at x=10 y=16\n",
+        )
+        .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 2, column 43: expected x=<x>, y=<y>, found \"x=10 y=16\""
+        );
+
+        let err = parse_lines::<Report>("Sensor at x=2, y=18\n").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 1, column 11: expected a sensor and its closest beacon, found \"x=2, y=18\""
+        );
+    }
+}
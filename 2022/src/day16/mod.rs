@@ -0,0 +1,262 @@
+use std::str::FromStr;
+
+use aoc_utils::{bfs, parse_lines, parse_number, Answer, ParseError, Paths, Solution};
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Valve {
+    pub name: String,
+    pub flow_rate: usize,
+    /// The names of the valves one minute away.
+    pub tunnels: Vec<String>,
+}
+
+impl FromStr for Valve {
+    type Err = ParseError;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let rest = input
+            .strip_prefix("Valve ")
+            .ok_or_else(|| ParseError::new(input, "\"Valve\""))?;
+        let (name, rest) = rest.split_once(" has flow rate=").ok_or_else(|| {
+            ParseError::new(rest, "a valve and its flow rate").within(input, rest)
+        })?;
+        let (flow_rate, rest) = rest
+            .split_once("; ")
+            .ok_or_else(|| ParseError::new(rest, "a flow rate and tunnels").within(input, rest))?;
+        let tunnels = rest
+            .strip_prefix("tunnels lead to valves ")
+            .or_else(|| rest.strip_prefix("tunnel leads to valve "))
+            .ok_or_else(|| {
+                ParseError::new(rest, "the valves tunnels lead to").within(input, rest)
+            })?;
+
+        Ok(Self {
+            name: name.to_owned(),
+            flow_rate: parse_number(input, flow_rate)?,
+            tunnels: tunnels.split(", ").map(str::to_owned).collect(),
+        })
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Network {
+    pub valves: Vec<Valve>,
+}
+
+impl FromStr for Network {
+    type Err = ParseError;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let network = Self {
+            valves: parse_lines(input)?,
+        };
+        for (line, valve) in input.lines().zip(&network.valves) {
+            let listed = &line[line.len() - valve.tunnels.join(", ").len()..];
+            if let Some(unknown) = listed
+                .split(", ")
+                .find(|name| network.find_by_name(name).is_none())
+            {
+                return Err(
+                    ParseError::new(unknown, "a valve of the network").within(input, unknown)
+                );
+            }
+        }
+        Ok(network)
+    }
+}
+
+impl Network {
+    pub fn find_by_name(&self, name: &str) -> Option<&Valve> {
+        self.valves.iter().find(|valve| valve.name == name)
+    }
+
+    /// How many minutes it takes to walk from `from` to every valve.
+    pub fn distances_from<'a>(&'a self, from: &'a str) -> Paths<&'a str> {
+        bfs(from, |name| {
+            self.find_by_name(name)
+                .into_iter()
+                .flat_map(|valve| valve.tunnels.iter().map(String::as_str))
+        })
+    }
+
+    /// The network cut down to the valves worth opening, from `start`.
+    pub fn compress(&self, start: &str) -> Graph {
+        let useful: Vec<&Valve> = self
+            .valves
+            .iter()
+            .filter(|valve| valve.flow_rate > 0)
+            .collect();
+        let distances = useful
+            .iter()
+            .map(|valve| valve.name.as_str())
+            .chain([start])
+            .map(|from| {
+                let paths = self.distances_from(from);
+                useful
+                    .iter()
+                    .map(|to| paths.distance(&to.name.as_str()))
+                    .collect()
+            })
+            .collect();
+        Graph {
+            flow_rates: useful.iter().map(|valve| valve.flow_rate).collect(),
+            distances,
+        }
+    }
+}
+
+/// The network cut down to the valves worth opening, with the minutes it takes
+/// to walk between them.
+#[derive(Debug, Clone)]
+pub struct Graph {
+    pub flow_rates: Vec<usize>,
+    /// From each valve, then from the start as the last row, to each valve.
+    pub distances: Vec<Vec<Option<usize>>>,
+}
+
+impl Graph {
+    /// For each set of valves, as a bit mask, the most pressure released in
+    /// `minutes` by opening exactly them, or 0 if they can't all be opened.
+    pub fn best_by_opened(&self, minutes: usize) -> Vec<usize> {
+        let mut best = vec![0; 1 << self.flow_rates.len()];
+        self.open_from(self.flow_rates.len(), minutes, 0, 0, &mut best);
+        best
+    }
+
+    fn open_from(
+        &self,
+        at: usize,
+        minutes: usize,
+        opened: usize,
+        released: usize,
+        best: &mut [usize],
+    ) {
+        best[opened] = best[opened].max(released);
+        for (next, &flow_rate) in self.flow_rates.iter().enumerate() {
+            if opened & 1 << next != 0 {
+                continue;
+            }
+            let Some(distance) = self.distances[at][next] else {
+                continue;
+            };
+            // Walking there and opening it, with time left for it to release.
+            let Some(minutes @ 1..) = minutes.checked_sub(distance + 1) else {
+                continue;
+            };
+            self.open_from(
+                next,
+                minutes,
+                opened | 1 << next,
+                released + minutes * flow_rate,
+                best,
+            );
+        }
+    }
+
+    /// The most pressure released alone in `minutes`.
+    pub fn most_pressure(&self, minutes: usize) -> usize {
+        self.best_by_opened(minutes)
+            .into_iter()
+            .max()
+            .unwrap_or_default()
+    }
+
+    /// The most pressure released in `minutes` with an elephant, each opening
+    /// valves the other doesn't.
+    pub fn most_pressure_with_elephant(&self, minutes: usize) -> usize {
+        let best = self.best_by_opened(minutes);
+        // The best of each set and all its subsets, removing one valve at a time.
+        let mut within = best.clone();
+        for opened in 0..within.len() {
+            for valve in 0..self.flow_rates.len() {
+                if opened & 1 << valve != 0 {
+                    within[opened] = within[opened].max(within[opened ^ 1 << valve]);
+                }
+            }
+        }
+        let all = best.len() - 1;
+        (0..best.len())
+            .map(|opened| best[opened] + within[all ^ opened])
+            .max()
+            .unwrap_or_default()
+    }
+}
+
+pub const START: &str = "AA";
+pub const MINUTES: usize = 30;
+/// What is left after teaching the elephant.
+pub const MINUTES_WITH_ELEPHANT: usize = 26;
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    type Input = Network;
+
+    fn parse(input: &str) -> anyhow::Result<Self::Input> {
+        Ok(input.parse()?)
+    }
+
+    fn part1(network: &Self::Input) -> anyhow::Result<Answer> {
+        Ok(network.compress(START).most_pressure(MINUTES).into())
+    }
+
+    fn part2(network: &Self::Input) -> anyhow::Result<Answer> {
+        Ok(network
+            .compress(START)
+            .most_pressure_with_elephant(MINUTES_WITH_ELEPHANT)
+            .into())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{Network, MINUTES, MINUTES_WITH_ELEPHANT, START};
+
+    #[test]
+    fn distances() {
+        let network: Network = include_str!("example").parse().unwrap();
+        assert_eq!(network.valves.len(), 10);
+        assert_eq!(network.find_by_name("HH").unwrap().tunnels, vec!["GG"]);
+
+        let paths = network.distances_from("AA");
+        assert_eq!(paths.distance(&"HH"), Some(5));
+        assert_eq!(
+            paths.path_to(&"HH").unwrap(),
+            vec!["AA", "DD", "EE", "FF", "GG", "HH"]
+        );
+    }
+
+    #[test]
+    fn pressure() {
+        let network: Network = include_str!("example").parse().unwrap();
+        let graph = network.compress(START);
+        assert_eq!(graph.flow_rates, vec![13, 2, 20, 3, 22, 21]);
+        assert_eq!(graph.distances[6], [1, 2, 1, 2, 5, 2].map(Some));
+
+        assert_eq!(graph.most_pressure(MINUTES), 1651);
+        assert_eq!(
+            graph.most_pressure_with_elephant(MINUTES_WITH_ELEPHANT),
+            1707
+        );
+    }
+
+    #[test]
+    fn bad_valves() {
+        let err = "Valve AA has flow rate=x; tunnel leads to valve BB\n"
+            .parse::<Network>()
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 1, column 24: expected a number, found \"x\""
+        );
+
+        let err = "Valve AA has flow rate=0; tunnel leads to valve BB\n\
+                   Valve BB has flow rate=3; tunnels lead to valves AA, CC\n"
+            .parse::<Network>()
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 2, column 54: expected a valve of the network, found \"CC\""
+        );
+    }
+}
@@ -0,0 +1,175 @@
+use std::str::FromStr;
+
+use aoc_utils::{parse_lines, parse_within, Answer, ParseError, Solution};
+
+type Score = usize;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Choice {
+    Rock,
+    Paper,
+    Scissor,
+}
+
+impl FromStr for Choice {
+    type Err = ParseError;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        match input {
+            "A" => Ok(Self::Rock),
+            "B" => Ok(Self::Paper),
+            "C" => Ok(Self::Scissor),
+            _ => Err(ParseError::new(input, "A, B or C")),
+        }
+    }
+}
+
+impl From<Choice> for Score {
+    fn from(choice: Choice) -> Self {
+        match choice {
+            Choice::Rock => 1,
+            Choice::Paper => 2,
+            Choice::Scissor => 3,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct Round {
+    // pub my_choice: Choice,
+    pub opponent_choice: Choice,
+    pub expected_result: RoundResult,
+}
+
+impl FromStr for Round {
+    type Err = ParseError;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let (opponent_choice, expected_result) = input
+            .split_once(' ')
+            .ok_or_else(|| ParseError::new(input, "a choice and a result"))?;
+        Ok(Self {
+            expected_result: parse_within(input, expected_result)?,
+            opponent_choice: parse_within(input, opponent_choice)?,
+        })
+    }
+}
+
+impl Round {
+    pub fn score(self) -> Score {
+        let my_choice: Choice = self.into();
+        // let result: RoundResult = self.into();
+        let result_score: Score = my_choice.into();
+        let expected_result: Score = self.expected_result.into();
+
+        result_score + expected_result
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+pub enum RoundResult {
+    Win,
+    Draw,
+    Defeat,
+}
+
+impl From<RoundResult> for Score {
+    fn from(result: RoundResult) -> Self {
+        match result {
+            RoundResult::Win => 6,
+            RoundResult::Draw => 3,
+            RoundResult::Defeat => 0,
+        }
+    }
+}
+
+impl FromStr for RoundResult {
+    type Err = ParseError;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        match input {
+            "X" => Ok(Self::Defeat),
+            "Y" => Ok(Self::Draw),
+            "Z" => Ok(Self::Win),
+            _ => Err(ParseError::new(input, "X, Y or Z")),
+        }
+    }
+}
+
+impl From<Round> for Choice {
+    fn from(round: Round) -> Self {
+        match (round.opponent_choice, round.expected_result) {
+            (Choice::Rock, RoundResult::Win) => Self::Paper,
+            (Choice::Rock, RoundResult::Draw) => Self::Rock,
+            (Choice::Rock, RoundResult::Defeat) => Self::Scissor,
+            (Choice::Paper, RoundResult::Win) => Self::Scissor,
+            (Choice::Paper, RoundResult::Draw) => Self::Paper,
+            (Choice::Paper, RoundResult::Defeat) => Self::Rock,
+            (Choice::Scissor, RoundResult::Win) => Self::Rock,
+            (Choice::Scissor, RoundResult::Draw) => Self::Scissor,
+            (Choice::Scissor, RoundResult::Defeat) => Self::Paper,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct StrategyGuide {
+    pub rounds: Vec<Round>,
+}
+
+impl StrategyGuide {
+    pub fn score(&self) -> Score {
+        self.rounds.iter().map(|round| round.score()).sum()
+    }
+}
+
+impl FromStr for StrategyGuide {
+    type Err = ParseError;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        Ok(Self {
+            rounds: parse_lines(input)?,
+        })
+    }
+}
+
+pub struct Day2;
+
+impl Solution for Day2 {
+    type Input = StrategyGuide;
+
+    fn parse(input: &str) -> anyhow::Result<Self::Input> {
+        Ok(input.parse()?)
+    }
+
+    fn part2(strategy_guide: &Self::Input) -> anyhow::Result<Answer> {
+        Ok(strategy_guide.score().into())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::StrategyGuide;
+
+    #[test]
+    fn example() {
+        let example = include_str!("example");
+        let strategy_guide: StrategyGuide = example.parse().unwrap();
+        assert_eq!(strategy_guide.score(), 12)
+    }
+
+    #[test]
+    fn bad_round() {
+        let err = "A Y\nB X\nC W\n".parse::<StrategyGuide>().unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 3, column 3: expected X, Y or Z, found \"W\""
+        );
+
+        let err = "A Y\n\nC Z\n".parse::<StrategyGuide>().unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 2, column 1: expected a choice and a result, found nothing"
+        );
+    }
+}
@@ -1,13 +1,41 @@
+use std::str::FromStr;
+
+use anyhow::Context;
+
+use aoc_utils::{parse_lines, Answer, ParseError, Solution};
+
 #[derive(Clone, Debug)]
 pub struct Rucksack {
     pub items: String,
 }
 
+impl FromStr for Rucksack {
+    type Err = ParseError;
+
+    fn from_str(items: &str) -> Result<Self, Self::Err> {
+        if let Some((i, item)) = items
+            .char_indices()
+            .find(|(_, item)| !item.is_ascii_alphabetic())
+        {
+            let found = &items[i..i + item.len_utf8()];
+            return Err(
+                ParseError::new(found, "an item, from a to z or A to Z").within(items, found)
+            );
+        }
+        if !items.len().is_multiple_of(2) {
+            return Err(ParseError::new(items, "as many items in both compartments"));
+        }
+        Ok(Self {
+            items: items.to_string(),
+        })
+    }
+}
+
 impl Rucksack {
     pub fn common_in_compartments(self) -> String {
         let compartments: Vec<Compartment> = self.into();
         common_chars(
-            compartments.get(0).unwrap().items.clone(),
+            compartments.first().unwrap().items.clone(),
             compartments.get(1).unwrap().items.clone(),
         )
     }
@@ -33,18 +61,18 @@ pub fn priority(c: char) -> usize {
 pub fn rucksacks_priority(rucksacks: Vec<Rucksack>) -> usize {
     priority(
         common_chars_3(
-            rucksacks.get(0).unwrap().items.clone(),
+            rucksacks.first().unwrap().items.clone(),
             rucksacks.get(1).unwrap().items.clone(),
             rucksacks.get(2).unwrap().items.clone(),
         )
         .chars()
-        .nth(0)
+        .next()
         .unwrap_or_default(),
     )
 }
 
 #[derive(Clone, Debug)]
-struct Compartment {
+pub struct Compartment {
     pub items: String,
 }
 
@@ -69,7 +97,7 @@ impl From<Rucksack> for Vec<Compartment> {
 pub fn common_chars(s1: String, s2: String) -> String {
     let mut chars: Vec<char> = s1
         .chars()
-        .filter(|c1| s2.chars().any(|c2| c2.eq(&c1)))
+        .filter(|c1| s2.chars().any(|c2| c2.eq(c1)))
         .collect();
     chars.dedup();
     String::from_iter(chars)
@@ -78,8 +106,8 @@ pub fn common_chars(s1: String, s2: String) -> String {
 pub fn common_chars_3(s1: String, s2: String, s3: String) -> String {
     let mut chars: Vec<char> = s1
         .chars()
-        .filter(|c1| s2.chars().any(|c2| c2.eq(&c1)))
-        .filter(|c1| s3.chars().any(|c3| c3.eq(&c1)))
+        .filter(|c1| s2.chars().any(|c2| c2.eq(c1)))
+        .filter(|c1| s3.chars().any(|c3| c3.eq(c1)))
         .collect();
     chars.dedup();
     String::from_iter(chars)
@@ -90,26 +118,39 @@ pub struct Rucksacks {
     pub rucksacks: Vec<Rucksack>,
 }
 
-impl From<&str> for Rucksacks {
-    fn from(input: &str) -> Self {
-        Self {
-            rucksacks: input
-                .lines()
-                .map(|line| Rucksack {
-                    items: line.to_string(),
-                })
-                .collect(),
-        }
+impl FromStr for Rucksacks {
+    type Err = ParseError;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        Ok(Self {
+            rucksacks: parse_lines(input)?,
+        })
     }
 }
 
 impl Rucksacks {
-    pub fn sum_priorities(self) -> usize {
+    pub fn sum_priorities(&self) -> usize {
         self.rucksacks
             .iter()
             .map(|rucksack| rucksack.clone().priority())
             .sum()
     }
+
+    pub fn groups(&self) -> Option<Groups> {
+        if !self.rucksacks.len().is_multiple_of(3) {
+            return None;
+        }
+        let groups = self
+            .rucksacks
+            .chunks(3)
+            .map(|rucksacks| Group {
+                rucksacks: Rucksacks {
+                    rucksacks: rucksacks.to_vec(),
+                },
+            })
+            .collect();
+        Some(Groups { groups })
+    }
 }
 
 #[derive(Clone, Debug)]
@@ -118,60 +159,60 @@ pub struct Group {
 }
 
 impl Group {
-    pub fn sum_priorities(self) -> usize {
-        rucksacks_priority(self.rucksacks.rucksacks)
+    pub fn sum_priorities(&self) -> usize {
+        rucksacks_priority(self.rucksacks.rucksacks.clone())
     }
 }
 
+#[derive(Debug)]
 pub struct Groups {
     pub groups: Vec<Group>,
 }
 
 impl Groups {
-    pub fn sum_priorities(self) -> usize {
-        self.groups
-            .iter()
-            .map(|group| group.clone().sum_priorities())
-            .sum()
+    pub fn sum_priorities(&self) -> usize {
+        self.groups.iter().map(|group| group.sum_priorities()).sum()
     }
 }
 
-impl From<&str> for Groups {
-    fn from(input: &str) -> Self {
-        let groups: Vec<Group> = input
-            .lines()
-            .enumerate()
-            .fold(Vec::new(), |mut acc, (i, line)| {
-                if i % 3 == 0 {
-                    acc.push(vec![line]);
-                } else {
-                    let last = acc.last_mut().unwrap();
-                    last.push(line);
-                }
-                acc
-            })
-            .iter()
-            .map(|elems| Group {
-                rucksacks: Rucksacks {
-                    rucksacks: elems
-                        .iter()
-                        .map(|elem| Rucksack {
-                            items: elem.to_string(),
-                        })
-                        .collect(),
-                },
-            })
-            .collect();
-        groups.iter().for_each(|e| {
-            dbg!(&e);
-        });
-        Self { groups }
+impl FromStr for Groups {
+    type Err = ParseError;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let rucksacks: Rucksacks = input.parse()?;
+        rucksacks.groups().ok_or_else(|| {
+            let end = &input[input.len()..];
+            ParseError::new(end, "groups of 3 rucksacks").within(input, end)
+        })
+    }
+}
+
+pub struct Day3;
+
+impl Solution for Day3 {
+    type Input = Rucksacks;
+
+    fn parse(input: &str) -> anyhow::Result<Self::Input> {
+        Ok(input.parse()?)
+    }
+
+    fn part1(rucksacks: &Self::Input) -> anyhow::Result<Answer> {
+        Ok(rucksacks.sum_priorities().into())
+    }
+
+    fn part2(rucksacks: &Self::Input) -> anyhow::Result<Answer> {
+        let groups = rucksacks
+            .groups()
+            .context("expected groups of 3 rucksacks")?;
+        Ok(groups.sum_priorities().into())
     }
 }
 
 #[cfg(test)]
 mod test {
-    use crate::{common_chars, common_chars_3, Compartment, Groups, Rucksack, Rucksacks};
+    use aoc_utils::Solution;
+
+    use super::{common_chars, common_chars_3, Compartment, Day3, Groups, Rucksack, Rucksacks};
 
     #[test]
     fn test_rucksack_to_vec_of_compartments_1() {
@@ -180,7 +221,7 @@ mod test {
         }
         .into();
         assert_eq!(
-            compartments.get(0).unwrap().items,
+            compartments.first().unwrap().items,
             "vJrwpWtwJgWr".to_string()
         );
         assert_eq!(
@@ -196,7 +237,7 @@ mod test {
         }
         .into();
         assert_eq!(
-            compartments.get(0).unwrap().items,
+            compartments.first().unwrap().items,
             "jqHRNqRjqzjGDLGL".to_string()
         );
         assert_eq!(
@@ -269,7 +310,7 @@ mod test {
 
     #[test]
     fn test_rucksacks_priority() {
-        let rucksacks: Rucksacks = include_str!("example").into();
+        let rucksacks: Rucksacks = include_str!("example").parse().unwrap();
         assert_eq!(rucksacks.sum_priorities(), 157);
     }
 
@@ -290,13 +331,32 @@ mod test {
     #[test]
     fn test_groups_sum_priotities() {
         let input = include_str!("example");
-        let rucksacks: Groups = input.into();
+        let rucksacks: Groups = input.parse().unwrap();
         assert_eq!(rucksacks.sum_priorities(), 70);
     }
-}
 
-fn main() {
-    let input = include_str!("input");
-    let rucksacks: Groups = input.into();
-    dbg!(rucksacks.sum_priorities());
+    #[test]
+    fn bad_rucksacks() {
+        let err = "vJrw\nab1c\n".parse::<Rucksacks>().unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 2, column 3: expected an item, from a to z or A to Z, found \"1\""
+        );
+
+        let err = "ab\ncd\n".parse::<Groups>().unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 3, column 1: expected groups of 3 rucksacks, found nothing"
+        );
+    }
+
+    #[test]
+    fn ungrouped_rucksacks() {
+        let rucksacks = Day3::parse("aa\nbb\ncc\ndd\n").unwrap();
+        assert_eq!(Day3::part1(&rucksacks).unwrap(), 10usize.into());
+        assert_eq!(
+            Day3::part2(&rucksacks).unwrap_err().to_string(),
+            "expected groups of 3 rucksacks"
+        );
+    }
 }
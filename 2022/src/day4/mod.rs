@@ -0,0 +1,206 @@
+use std::{ops::RangeInclusive, str::FromStr};
+
+use aoc_utils::{
+    parse_lines, parse_number, parse_within, Answer, IntervalSet, ParseError, Solution,
+};
+
+#[derive(Clone, Debug)]
+pub struct Section {
+    pub from: usize,
+    pub to: usize,
+}
+
+impl FromStr for Section {
+    type Err = ParseError;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let (from, to) = input
+            .split_once('-')
+            .ok_or_else(|| ParseError::new(input, "a section like 2-4"))?;
+        Ok(Self {
+            from: parse_number(input, from)?,
+            to: parse_number(input, to)?,
+        })
+    }
+}
+
+impl Section {
+    pub fn range(&self) -> RangeInclusive<usize> {
+        RangeInclusive::new(self.from, self.to)
+    }
+
+    pub fn set(&self) -> IntervalSet<usize> {
+        self.range().into()
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct Pair {
+    pub first: Section,
+    pub second: Section,
+}
+
+impl FromStr for Pair {
+    type Err = ParseError;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let (first, second) = input
+            .split_once(',')
+            .ok_or_else(|| ParseError::new(input, "two sections separated by a comma"))?;
+        Ok(Self {
+            first: parse_within(input, first)?,
+            second: parse_within(input, second)?,
+        })
+    }
+}
+
+impl Pair {
+    /// Whether one of the sections contains the other.
+    pub fn overlaps(&self) -> bool {
+        let (first, second) = (self.first.set(), self.second.set());
+        first.is_superset(&second) || second.is_superset(&first)
+    }
+
+    pub fn partially_overlaps(&self) -> bool {
+        !self.first.set().intersection(&self.second.set()).is_empty()
+    }
+}
+
+pub fn count_overlaping_pairs(pairs: &[Pair]) -> usize {
+    pairs.iter().filter(|pair| pair.overlaps()).count()
+}
+
+pub fn count_partially_overlaping_pairs(pairs: &[Pair]) -> usize {
+    pairs
+        .iter()
+        .filter(|pair| pair.partially_overlaps())
+        .count()
+}
+
+pub struct Day4;
+
+impl Solution for Day4 {
+    type Input = Vec<Pair>;
+
+    fn parse(input: &str) -> anyhow::Result<Self::Input> {
+        Ok(parse_lines(input)?)
+    }
+
+    fn part1(input: &Self::Input) -> anyhow::Result<Answer> {
+        Ok(count_overlaping_pairs(input).into())
+    }
+
+    fn part2(input: &Self::Input) -> anyhow::Result<Answer> {
+        Ok(count_partially_overlaping_pairs(input).into())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use aoc_utils::parse_lines;
+
+    use super::{count_overlaping_pairs, count_partially_overlaping_pairs, Pair, Section};
+
+    #[test]
+    fn test_section() {
+        let section: Section = "2-4".parse().unwrap();
+        assert_eq!(section.from, 2);
+        assert_eq!(section.to, 4);
+    }
+
+    #[test]
+    fn test_pair() {
+        let pair: Pair = "2-4,6-8".parse().unwrap();
+        assert_eq!(pair.first.from, 2);
+        assert_eq!(pair.first.to, 4);
+        assert_eq!(pair.second.from, 6);
+        assert_eq!(pair.second.to, 8);
+    }
+
+    #[test]
+    fn test_pair_overlaps_1() {
+        assert!(!"2-4,6-8".parse::<Pair>().unwrap().overlaps());
+    }
+
+    #[test]
+    fn test_pair_overlaps_2() {
+        assert!(!"2-3,4-5".parse::<Pair>().unwrap().overlaps());
+    }
+
+    #[test]
+    fn test_pair_overlaps_3() {
+        assert!(!"5-7,7-9".parse::<Pair>().unwrap().overlaps());
+    }
+
+    #[test]
+    fn test_pair_overlaps_4() {
+        assert!("2-8,3-7".parse::<Pair>().unwrap().overlaps());
+    }
+
+    #[test]
+    fn test_pair_overlaps_5() {
+        assert!("6-6,4-6".parse::<Pair>().unwrap().overlaps());
+    }
+
+    #[test]
+    fn test_pair_overlaps_6() {
+        assert!(!"2-6,4-8".parse::<Pair>().unwrap().overlaps());
+    }
+
+    #[test]
+    fn test_pair_partially_overlaps_1() {
+        assert!(!"2-4,6-8".parse::<Pair>().unwrap().partially_overlaps());
+    }
+
+    #[test]
+    fn test_pair_partially_overlaps_2() {
+        assert!(!"2-3,4-5".parse::<Pair>().unwrap().partially_overlaps());
+    }
+
+    #[test]
+    fn test_pair_partially_overlaps_3() {
+        assert!("5-7,7-9".parse::<Pair>().unwrap().partially_overlaps());
+    }
+
+    #[test]
+    fn test_pair_partially_overlaps_4() {
+        assert!("2-8,3-7".parse::<Pair>().unwrap().partially_overlaps());
+    }
+
+    #[test]
+    fn test_pair_partially_overlaps_5() {
+        assert!("6-6,4-6".parse::<Pair>().unwrap().partially_overlaps());
+    }
+
+    #[test]
+    fn test_pair_partially_overlaps_6() {
+        assert!("2-6,4-8".parse::<Pair>().unwrap().partially_overlaps());
+    }
+
+    #[test]
+    fn example_part1() {
+        let pairs: Vec<Pair> = parse_lines(include_str!("example")).unwrap();
+        assert_eq!(count_overlaping_pairs(&pairs), 2);
+    }
+
+    #[test]
+    fn example_part2() {
+        let pairs: Vec<Pair> = parse_lines(include_str!("example")).unwrap();
+        assert_eq!(count_partially_overlaping_pairs(&pairs), 4);
+    }
+
+    #[test]
+    fn bad_pairs() {
+        let err = parse_lines::<Pair>("2-4,6-8\n2-3,4_5\n").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 2, column 5: expected a section like 2-4, found \"4_5\""
+        );
+
+        let err = parse_lines::<Pair>("2-4,6-8\n2-3,4-x\n").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 2, column 7: expected a number, found \"x\""
+        );
+    }
+}
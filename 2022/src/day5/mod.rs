@@ -0,0 +1,565 @@
+use std::{
+    error::Error,
+    fmt::{self, Display},
+    str::FromStr,
+};
+
+use anyhow::Context;
+use aoc_utils::{parse_lines, parse_number, Answer, ParseError, Solution};
+
+pub type Crate = String;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Crane {
+    pub stacks: Vec<Stack>,
+}
+
+/// The drawing of the puzzle: a row of `[A]` crates, or blanks, per level from
+/// the top, each 3 characters wide and a space apart, then the stack numbers.
+impl FromStr for Crane {
+    type Err = ParseError;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let mut lines: Vec<&str> = input.lines().collect();
+        let footer = lines
+            .pop()
+            .ok_or_else(|| ParseError::new(input, "a drawing of the stacks"))?;
+        let mut stacks = vec![];
+        for number in footer.split_whitespace() {
+            let expected = stacks.len() + 1;
+            if parse_number::<usize>(input, number)? != expected {
+                return Err(
+                    ParseError::new(number, format!("stack {expected}")).within(input, number)
+                );
+            }
+            stacks.push(Stack { crates: vec![] });
+        }
+
+        // From the bottom, so that crates are pushed in order.
+        for (level, &line) in lines.iter().rev().enumerate() {
+            if let Some((i, c)) = line.char_indices().find(|(_, c)| !c.is_ascii()) {
+                let found = &line[i..i + c.len_utf8()];
+                return Err(ParseError::new(found, "a crate like [A]").within(input, found));
+            }
+            for (i, start) in (0..line.len()).step_by(4).enumerate() {
+                let cell = &line[start..line.len().min(start + 3)];
+                let stack = stacks.get_mut(i).ok_or_else(|| {
+                    ParseError::new(cell, "nothing past the last stack").within(input, cell)
+                })?;
+                match cell.as_bytes() {
+                    [b' ', b' ', b' '] | [b' ', b' '] | [b' '] => {}
+                    &[b'[', c @ b'A'..=b'Z', b']'] => {
+                        if stack.crates.len() < level {
+                            return Err(ParseError::new(cell, "a crate on top of another")
+                                .within(input, cell));
+                        }
+                        stack.crates.push(c as char)
+                    }
+                    _ => {
+                        return Err(ParseError::new(cell, "a crate like [A], or nothing")
+                            .within(input, cell))
+                    }
+                }
+            }
+        }
+        Ok(Self { stacks })
+    }
+}
+
+/// The drawing it was parsed from.
+impl Display for Crane {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let height = self.stacks.iter().map(|stack| stack.crates.len()).max();
+        for level in (0..height.unwrap_or_default()).rev() {
+            let row: Vec<String> = self
+                .stacks
+                .iter()
+                .map(|stack| match stack.crates.get(level) {
+                    Some(c) => format!("[{c}]"),
+                    None => "   ".to_owned(),
+                })
+                .collect();
+            writeln!(f, "{}", row.join(" "))?;
+        }
+        let numbers: Vec<String> = (1..=self.stacks.len()).map(|i| format!(" {i} ")).collect();
+        write!(f, "{}", numbers.join(" "))
+    }
+}
+
+impl Crane {
+    /// The crate on top of each stack, or a space for an empty one.
+    pub fn message(&self) -> String {
+        self.stacks
+            .iter()
+            .map(|stack| stack.crates.last().copied().unwrap_or(' '))
+            .collect()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Stack {
+    /// From the bottom.
+    pub crates: Vec<char>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Move {
+    pub count: usize,
+    pub from_stack_id: usize,
+    pub to_stack_id: usize,
+}
+
+fn keyword(input: &str, token: &str, keyword: &str) -> Result<(), ParseError> {
+    if token == keyword {
+        Ok(())
+    } else {
+        Err(ParseError::new(token, format!("`{keyword}`")).within(input, token))
+    }
+}
+
+impl FromStr for Move {
+    type Err = ParseError;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let mut tokens = input.split(' ');
+        let mut token = || tokens.next().unwrap_or(&input[input.len()..]);
+
+        keyword(input, token(), "move")?;
+        let count = parse_number(input, token())?;
+        keyword(input, token(), "from")?;
+        let from_stack_id = parse_number(input, token())?;
+        keyword(input, token(), "to")?;
+        let to_stack_id = parse_number(input, token())?;
+        keyword(input, token(), "")?;
+
+        Ok(Self {
+            count,
+            from_stack_id,
+            to_stack_id,
+        })
+    }
+}
+
+impl Display for Move {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "move {} from {} to {}",
+            self.count, self.from_stack_id, self.to_stack_id
+        )
+    }
+}
+
+/// How a crane lays down the crates it takes off a stack.
+pub trait CraneModel {
+    /// The crates taken off the top of a stack, bottom first, in the order they
+    /// end up on the other stack, bottom first.
+    fn arrange(&self, lifted: Vec<char>) -> Vec<char>;
+}
+
+/// Lifts one crate at a time.
+pub struct CrateMover9000;
+
+impl CraneModel for CrateMover9000 {
+    fn arrange(&self, mut lifted: Vec<char>) -> Vec<char> {
+        lifted.reverse();
+        lifted
+    }
+}
+
+/// Lifts all the crates at once.
+pub struct CrateMover9001;
+
+impl CraneModel for CrateMover9001 {
+    fn arrange(&self, lifted: Vec<char>) -> Vec<char> {
+        lifted
+    }
+}
+
+/// Lifts up to `capacity` crates at a time, at least one.
+pub struct CappedCrane {
+    pub capacity: usize,
+}
+
+impl CraneModel for CappedCrane {
+    fn arrange(&self, lifted: Vec<char>) -> Vec<char> {
+        lifted
+            .rchunks(self.capacity.max(1))
+            .flatten()
+            .copied()
+            .collect()
+    }
+}
+
+/// Why a move can't be made.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Invalid {
+    NoStack(usize),
+    NotEnoughCrates {
+        stack: usize,
+        count: usize,
+        available: usize,
+    },
+}
+
+impl Display for Invalid {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::NoStack(stack) => write!(f, "there is no stack {stack}"),
+            Self::NotEnoughCrates {
+                stack,
+                count,
+                available,
+            } => write!(f, "stack {stack} has {available} crates, not {count}"),
+        }
+    }
+}
+
+/// A move of a list that can't be made.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MoveError {
+    /// Starting at 1.
+    pub step: usize,
+    pub movement: Move,
+    pub reason: Invalid,
+}
+
+impl Display for MoveError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "move #{} `{}`: {}",
+            self.step, self.movement, self.reason
+        )
+    }
+}
+
+impl Error for MoveError {}
+
+impl Crane {
+    fn stack(&self, id: usize) -> Result<&Stack, Invalid> {
+        id.checked_sub(1)
+            .and_then(|i| self.stacks.get(i))
+            .ok_or(Invalid::NoStack(id))
+    }
+
+    /// Whether `movement` can be made.
+    pub fn check(&self, movement: &Move) -> Result<(), Invalid> {
+        let available = self.stack(movement.from_stack_id)?.crates.len();
+        self.stack(movement.to_stack_id)?;
+        if available < movement.count {
+            return Err(Invalid::NotEnoughCrates {
+                stack: movement.from_stack_id,
+                count: movement.count,
+                available,
+            });
+        }
+        Ok(())
+    }
+
+    /// Makes `movement` with `model`, unless it can't be made.
+    pub fn apply(&mut self, model: &dyn CraneModel, movement: &Move) -> Result<(), Invalid> {
+        self.check(movement)?;
+        // Whatever the crane lifts goes back where it was.
+        if movement.from_stack_id == movement.to_stack_id {
+            return Ok(());
+        }
+        let from = &mut self.stacks[movement.from_stack_id - 1].crates;
+        let lifted = from.split_off(from.len() - movement.count);
+        self.stacks[movement.to_stack_id - 1]
+            .crates
+            .extend(model.arrange(lifted));
+        Ok(())
+    }
+}
+
+/// A crane making moves one by one, keeping every state it went through so
+/// that moves can be undone and replayed.
+pub struct Simulation<'a> {
+    pub model: &'a dyn CraneModel,
+    pub moves: &'a [Move],
+    /// The initial state, then the state after each move made.
+    states: Vec<Crane>,
+}
+
+impl<'a> Simulation<'a> {
+    pub fn new(crane: Crane, model: &'a dyn CraneModel, moves: &'a [Move]) -> Self {
+        Self {
+            model,
+            moves,
+            states: vec![crane],
+        }
+    }
+
+    pub fn crane(&self) -> &Crane {
+        self.states.last().unwrap()
+    }
+
+    /// The states so far, from the initial one.
+    pub fn history(&self) -> &[Crane] {
+        &self.states
+    }
+
+    /// How many moves were made.
+    pub fn steps(&self) -> usize {
+        self.states.len() - 1
+    }
+
+    /// Makes the next move, if any is left.
+    pub fn step(&mut self) -> Result<Option<&Move>, MoveError> {
+        let Some(movement) = self.moves.get(self.steps()) else {
+            return Ok(None);
+        };
+        let mut crane = self.crane().clone();
+        crane
+            .apply(self.model, movement)
+            .map_err(|reason| MoveError {
+                step: self.steps() + 1,
+                movement: movement.clone(),
+                reason,
+            })?;
+        self.states.push(crane);
+        Ok(Some(movement))
+    }
+
+    /// Takes back the last move made, if any.
+    pub fn undo(&mut self) -> Option<&Move> {
+        if self.steps() == 0 {
+            return None;
+        }
+        self.states.pop();
+        self.moves.get(self.steps())
+    }
+
+    /// Makes all the moves left.
+    pub fn run(&mut self) -> Result<&Crane, MoveError> {
+        while self.step()?.is_some() {}
+        Ok(self.crane())
+    }
+}
+
+pub fn parse_crane_and_moves(input: &str) -> anyhow::Result<(Crane, Vec<Move>)> {
+    let (init, moves) = input
+        .split_once("\n\n")
+        .context("missing blank line between the stacks and the moves")?;
+    let crane: Crane = init.parse()?;
+    let moves = parse_lines(moves).map_err(|err| err.within(input, moves))?;
+    Ok((crane, moves))
+}
+
+pub struct Day5;
+
+impl Solution for Day5 {
+    type Input = (Crane, Vec<Move>);
+
+    fn parse(input: &str) -> anyhow::Result<Self::Input> {
+        parse_crane_and_moves(input)
+    }
+
+    fn part1((crane, moves): &Self::Input) -> anyhow::Result<Answer> {
+        let mut simulation = Simulation::new(crane.clone(), &CrateMover9000, moves);
+        Ok(simulation.run()?.message().into())
+    }
+
+    fn part2((crane, moves): &Self::Input) -> anyhow::Result<Answer> {
+        let mut simulation = Simulation::new(crane.clone(), &CrateMover9001, moves);
+        Ok(simulation.run()?.message().into())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{
+        parse_crane_and_moves, CappedCrane, Crane, CraneModel, CrateMover9000, CrateMover9001,
+        Invalid, Move, MoveError, Simulation,
+    };
+
+    #[test]
+    fn check_example_init() {
+        let (crane, _) = parse_crane_and_moves(include_str!("example")).unwrap();
+        let stacks: Vec<String> = crane
+            .stacks
+            .iter()
+            .map(|stack| stack.crates.iter().collect())
+            .collect();
+        assert_eq!(stacks, vec!["ZN", "MCD", "P"]);
+        assert_eq!(
+            crane.to_string(),
+            include_str!("example").split_once("\n\n").unwrap().0
+        );
+    }
+
+    #[test]
+    fn empty_stacks() {
+        let crane: Crane = "[A]    \n[B]     [C]\n 1   2   3".parse().unwrap();
+        assert_eq!(crane.stacks[1].crates, vec![]);
+        assert_eq!(crane.message(), "A C");
+        // Trailing blanks may be left out.
+        assert_eq!("[A]\n[B]     [C]\n 1   2   3 ".parse(), Ok(crane));
+    }
+
+    #[test]
+    fn init_move() {
+        let m: Move = "move 12 from 42 to 36".parse().unwrap();
+        assert_eq!(m.count, 12);
+        assert_eq!(m.from_stack_id, 42);
+        assert_eq!(m.to_stack_id, 36);
+    }
+
+    #[test]
+    fn test_example() {
+        let (crane, moves) = parse_crane_and_moves(include_str!("example")).unwrap();
+        let mut simulation = Simulation::new(crane, &CrateMover9000, &moves);
+        assert_eq!(simulation.run().unwrap().message(), "CMZ");
+    }
+
+    #[test]
+    fn test_example_9001() {
+        let (crane, moves) = parse_crane_and_moves(include_str!("example")).unwrap();
+        let mut simulation = Simulation::new(crane, &CrateMover9001, &moves);
+        assert_eq!(simulation.run().unwrap().message(), "MCD");
+    }
+
+    #[test]
+    fn models() {
+        let lifted: Vec<char> = "abcde".chars().collect();
+        let arranged = |model: &dyn CraneModel| -> String {
+            model.arrange(lifted.clone()).into_iter().collect()
+        };
+        assert_eq!(arranged(&CrateMover9000), "edcba");
+        assert_eq!(arranged(&CrateMover9001), "abcde");
+        assert_eq!(arranged(&CappedCrane { capacity: 2 }), "debca");
+        assert_eq!(arranged(&CappedCrane { capacity: 1 }), "edcba");
+
+        let (crane, moves) = parse_crane_and_moves(include_str!("example")).unwrap();
+        let mut simulation = Simulation::new(crane, &CappedCrane { capacity: 2 }, &moves);
+        assert_eq!(simulation.run().unwrap().message(), "MCZ");
+    }
+
+    #[test]
+    fn same_stack() {
+        let (crane, _) = parse_crane_and_moves(include_str!("example")).unwrap();
+        let movement: Move = "move 2 from 2 to 2".parse().unwrap();
+        let models: [&dyn CraneModel; 3] = [
+            &CrateMover9000,
+            &CrateMover9001,
+            &CappedCrane { capacity: 2 },
+        ];
+        for model in models {
+            let mut moved = crane.clone();
+            moved.apply(model, &movement).unwrap();
+            assert_eq!(moved, crane);
+        }
+        let mut moved = crane.clone();
+        let too_many = "move 4 from 2 to 2".parse().unwrap();
+        assert!(moved.apply(&CrateMover9000, &too_many).is_err());
+    }
+
+    #[test]
+    fn undo_and_replay() {
+        let (crane, moves) = parse_crane_and_moves(include_str!("example")).unwrap();
+        let mut simulation = Simulation::new(crane.clone(), &CrateMover9000, &moves);
+        simulation.step().unwrap();
+        simulation.step().unwrap();
+        let second = simulation.crane().clone();
+        assert_eq!(simulation.crane().message(), " CZ");
+
+        assert_eq!(simulation.undo(), Some(&moves[1]));
+        assert_eq!(
+            simulation.history(),
+            [crane.clone(), simulation.crane().clone()]
+        );
+        assert_eq!(simulation.step().unwrap(), Some(&moves[1]));
+        assert_eq!(simulation.crane(), &second);
+
+        simulation.run().unwrap();
+        assert_eq!(simulation.history().len(), moves.len() + 1);
+        assert_eq!(simulation.step().unwrap(), None);
+        while simulation.undo().is_some() {}
+        assert_eq!(simulation.crane(), &crane);
+    }
+
+    #[test]
+    fn invalid_moves() {
+        let (crane, _) = parse_crane_and_moves(include_str!("example")).unwrap();
+        let moves: Vec<Move> = [
+            "move 1 from 2 to 1",
+            "move 3 from 1 to 3",
+            "move 2 from 1 to 3",
+        ]
+        .into_iter()
+        .map(|line| line.parse().unwrap())
+        .collect();
+        let mut simulation = Simulation::new(crane.clone(), &CrateMover9001, &moves);
+        let err = simulation.run().unwrap_err();
+        assert_eq!(
+            err,
+            MoveError {
+                step: 3,
+                movement: moves[2].clone(),
+                reason: Invalid::NotEnoughCrates {
+                    stack: 1,
+                    count: 2,
+                    available: 0
+                },
+            }
+        );
+        assert_eq!(
+            err.to_string(),
+            "move #3 `move 2 from 1 to 3`: stack 1 has 0 crates, not 2"
+        );
+        // The state before the move is kept.
+        assert_eq!(simulation.steps(), 2);
+
+        let moves = ["move 1 from 4 to 1".parse().unwrap()];
+        let err = Simulation::new(crane, &CrateMover9000, &moves)
+            .run()
+            .unwrap_err();
+        assert_eq!(err.reason, Invalid::NoStack(4));
+    }
+
+    #[test]
+    fn bad_moves() {
+        let crates = "[Z] [M]\n 1   2 \n\n";
+        let err =
+            parse_crane_and_moves(&format!("{crates}move 1 from 2 to 1\nmove 3 form 1 to 3\n"))
+                .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 5, column 8: expected `from`, found \"form\""
+        );
+
+        let err = parse_crane_and_moves(&format!("{crates}move 1 from 2 to\n")).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 4, column 17: expected a number, found nothing"
+        );
+    }
+
+    #[test]
+    fn bad_drawing() {
+        let err = parse_crane_and_moves("[Z] [c]\n 1   2 \n\nmove 1 from 2 to 1\n").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 1, column 5: expected a crate like [A], or nothing, found \"[c]\""
+        );
+
+        let err = "[Z] [M] [P]\n 1   2 ".parse::<Crane>().unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 1, column 9: expected nothing past the last stack, found \"[P]\""
+        );
+
+        let err = "    [D]\n[Z]    \n 1   2 ".parse::<Crane>().unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 1, column 5: expected a crate on top of another, found \"[D]\""
+        );
+
+        let err = "[Z] [M]\n 1   3 ".parse::<Crane>().unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 2, column 6: expected stack 2, found \"3\""
+        );
+    }
+}
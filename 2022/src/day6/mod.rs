@@ -0,0 +1,156 @@
+use std::io::{self, BufReader, Read};
+
+use anyhow::Context;
+use aoc_utils::{Answer, Solution};
+
+pub const PART1: usize = 4;
+pub const PART2: usize = 14;
+
+/// Spots markers, `length` different bytes in a row, in bytes fed one at a
+/// time, keeping only the last `length` of them.
+#[derive(Debug, Clone)]
+pub struct MarkerDetector {
+    /// The last bytes, as a ring.
+    window: Vec<u8>,
+    /// How many of each byte the window holds.
+    counts: [usize; 256],
+    distinct: usize,
+    position: usize,
+}
+
+impl MarkerDetector {
+    pub fn new(length: usize) -> Self {
+        assert!(length > 0, "a marker is at least one byte long");
+        Self {
+            window: vec![0; length],
+            counts: [0; 256],
+            distinct: 0,
+            position: 0,
+        }
+    }
+
+    /// How many bytes were fed.
+    pub fn position(&self) -> usize {
+        self.position
+    }
+
+    /// Feeds `byte`, telling whether it ends a marker.
+    pub fn push(&mut self, byte: u8) -> bool {
+        let length = self.window.len();
+        let slot = self.position % length;
+        if self.position >= length {
+            let old = &mut self.counts[self.window[slot] as usize];
+            *old -= 1;
+            if *old == 0 {
+                self.distinct -= 1;
+            }
+        }
+        self.window[slot] = byte;
+        let new = &mut self.counts[byte as usize];
+        *new += 1;
+        if *new == 1 {
+            self.distinct += 1;
+        }
+        self.position += 1;
+        self.distinct == length
+    }
+}
+
+/// How many bytes are read up to the end of each marker of `length` bytes.
+pub fn markers(bytes: impl IntoIterator<Item = u8>, length: usize) -> impl Iterator<Item = usize> {
+    let mut detector = MarkerDetector::new(length);
+    bytes
+        .into_iter()
+        .filter_map(move |byte| detector.push(byte).then_some(detector.position()))
+}
+
+/// [`markers`] of a stream, read as it goes.
+pub fn read_markers(reader: impl Read, length: usize) -> impl Iterator<Item = io::Result<usize>> {
+    let mut detector = MarkerDetector::new(length);
+    BufReader::new(reader)
+        .bytes()
+        .filter_map(move |byte| match byte {
+            Ok(byte) => detector.push(byte).then(|| Ok(detector.position())),
+            Err(err) => Some(Err(err)),
+        })
+}
+
+pub fn find_marker(input: &str, length: usize) -> Option<usize> {
+    markers(input.bytes(), length).next()
+}
+
+pub struct Day6;
+
+impl Solution for Day6 {
+    type Input = String;
+
+    fn parse(input: &str) -> anyhow::Result<Self::Input> {
+        Ok(input.to_string())
+    }
+
+    fn part1(input: &Self::Input) -> anyhow::Result<Answer> {
+        Ok(find_marker(input, PART1).context("no marker")?.into())
+    }
+
+    fn part2(input: &Self::Input) -> anyhow::Result<Answer> {
+        Ok(find_marker(input, PART2).context("no message")?.into())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::io::{self, Read};
+
+    use super::{find_marker, markers, read_markers, PART1, PART2};
+
+    #[test]
+    fn example() {
+        let input = include_str!("example");
+        assert_eq!(find_marker(input, PART1), Some(7));
+    }
+
+    #[test]
+    fn example2_1() {
+        let input = include_str!("example2_1");
+        assert_eq!(find_marker(input, PART2), Some(19));
+    }
+
+    #[test]
+    fn example2_2() {
+        let input = include_str!("example2_2");
+        assert_eq!(find_marker(input, PART2), Some(23));
+    }
+
+    #[test]
+    fn example2_3() {
+        let input = include_str!("example2_3");
+        assert_eq!(find_marker(input, PART2), Some(23));
+    }
+
+    #[test]
+    fn example2_4() {
+        let input = include_str!("example2_4");
+        assert_eq!(find_marker(input, PART2), Some(29));
+    }
+
+    #[test]
+    fn example2_5() {
+        let input = include_str!("example2_5");
+        assert_eq!(find_marker(input, PART2), Some(26));
+    }
+
+    #[test]
+    fn all_markers() {
+        assert_eq!(markers(*b"abcdab", 4).collect::<Vec<_>>(), vec![4, 5, 6]);
+        assert_eq!(markers(*b"abcabc", 4).next(), None);
+        assert_eq!(find_marker("aaaa", 1), Some(1));
+    }
+
+    #[test]
+    fn streams() {
+        // Far more than a window, never held in memory at once.
+        let stream = io::repeat(b'a').take(1 << 20).chain(&b"abcd"[..]);
+        let found: Vec<usize> = read_markers(stream, PART1).map(Result::unwrap).collect();
+        assert_eq!(found, vec![(1 << 20) + 4]);
+    }
+}
@@ -0,0 +1,819 @@
+use std::{
+    env,
+    fs::{self, File},
+    io,
+    path::{Path, PathBuf},
+    process,
+    str::FromStr,
+};
+
+use anyhow::Context;
+use aoc_utils::{parse_number, parse_within, Answer, ParseError, Solution};
+
+/// The index of a node in its [`FileSystem`].
+pub type NodeId = usize;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Kind {
+    Dir { children: Vec<NodeId> },
+    File { size: usize },
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Node {
+    pub name: String,
+    pub parent: Option<NodeId>,
+    pub kind: Kind,
+}
+
+impl Node {
+    pub fn is_dir(&self) -> bool {
+        matches!(self.kind, Kind::Dir { .. })
+    }
+}
+
+/// Directories and files, each node owned by this arena and pointing at the
+/// others by index.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FileSystem {
+    /// Removed nodes stay, out of the tree.
+    nodes: Vec<Node>,
+}
+
+impl Default for FileSystem {
+    fn default() -> Self {
+        Self {
+            nodes: vec![Node {
+                name: "/".to_owned(),
+                parent: None,
+                kind: Kind::Dir { children: vec![] },
+            }],
+        }
+    }
+}
+
+impl FileSystem {
+    pub const ROOT: NodeId = 0;
+
+    pub fn node(&self, id: NodeId) -> &Node {
+        &self.nodes[id]
+    }
+
+    pub fn children(&self, id: NodeId) -> &[NodeId] {
+        match &self.nodes[id].kind {
+            Kind::Dir { children } => children,
+            Kind::File { .. } => &[],
+        }
+    }
+
+    pub fn child(&self, dir: NodeId, name: &str) -> Option<NodeId> {
+        self.children(dir)
+            .iter()
+            .copied()
+            .find(|&child| self.nodes[child].name == name)
+    }
+
+    /// The directory `name` in `dir`, created unless it already exists.
+    pub fn add_dir(&mut self, dir: NodeId, name: &str) -> NodeId {
+        self.child(dir, name)
+            .unwrap_or_else(|| self.add(dir, name, Kind::Dir { children: vec![] }))
+    }
+
+    /// The file `name` in `dir`, created unless it already exists.
+    pub fn add_file(&mut self, dir: NodeId, name: &str, size: usize) -> NodeId {
+        self.child(dir, name)
+            .unwrap_or_else(|| self.add(dir, name, Kind::File { size }))
+    }
+
+    fn add(&mut self, dir: NodeId, name: &str, kind: Kind) -> NodeId {
+        let id = self.nodes.len();
+        self.nodes.push(Node {
+            name: name.to_owned(),
+            parent: Some(dir),
+            kind,
+        });
+        match &mut self.nodes[dir].kind {
+            Kind::Dir { children } => children.push(id),
+            Kind::File { .. } => panic!("{} isn't a directory", self.path(dir)),
+        }
+        id
+    }
+
+    /// Takes `id`, and all it contains, out of the tree.
+    pub fn remove(&mut self, id: NodeId) {
+        if let Some(parent) = self.nodes[id].parent.take() {
+            if let Kind::Dir { children } = &mut self.nodes[parent].kind {
+                children.retain(|&child| child != id);
+            }
+        }
+    }
+
+    /// The absolute path of `id`, like `/a/e`.
+    pub fn path(&self, id: NodeId) -> String {
+        let mut names = vec![];
+        let mut node = id;
+        while let Some(parent) = self.nodes[node].parent {
+            names.push(self.nodes[node].name.as_str());
+            node = parent;
+        }
+        names.reverse();
+        format!("/{}", names.join("/"))
+    }
+
+    /// The node at `path`, either absolute or relative to `dir`, going through
+    /// `.` and `..`.
+    pub fn resolve(&self, dir: NodeId, path: &str) -> Option<NodeId> {
+        let start = if path.starts_with('/') {
+            Self::ROOT
+        } else {
+            dir
+        };
+        path.split('/')
+            .filter(|name| !name.is_empty())
+            .try_fold(start, |dir, name| match name {
+                "." => Some(dir),
+                ".." => Some(self.nodes[dir].parent.unwrap_or(Self::ROOT)),
+                name => self.child(dir, name),
+            })
+    }
+
+    /// The node at the absolute `path`.
+    pub fn lookup(&self, path: &str) -> Option<NodeId> {
+        if !path.starts_with('/') {
+            return None;
+        }
+        self.resolve(Self::ROOT, path)
+    }
+
+    /// The size of every node, a directory weighing all it contains.
+    pub fn sizes(&self) -> Vec<usize> {
+        let mut sizes: Vec<usize> = self
+            .nodes
+            .iter()
+            .map(|node| match node.kind {
+                Kind::File { size } => size,
+                Kind::Dir { .. } => 0,
+            })
+            .collect();
+        // Nodes come after their parent.
+        for id in (1..self.nodes.len()).rev() {
+            if let Some(parent) = self.nodes[id].parent {
+                sizes[parent] += sizes[id];
+            }
+        }
+        sizes
+    }
+
+    pub fn size(&self, id: NodeId) -> usize {
+        match self.nodes[id].kind {
+            Kind::File { size } => size,
+            Kind::Dir { ref children } => children.iter().map(|&child| self.size(child)).sum(),
+        }
+    }
+
+    /// Every directory with its size, the contents of a directory before it.
+    pub fn dir_sizes(&self) -> Vec<(NodeId, usize)> {
+        let sizes = self.sizes();
+        let mut dirs = vec![];
+        self.walk_dirs(Self::ROOT, &mut |id| dirs.push((id, sizes[id])));
+        dirs
+    }
+
+    /// A line per directory with its size and path, like `du`.
+    pub fn du(&self) -> String {
+        self.dir_sizes()
+            .into_iter()
+            .map(|(id, size)| format!("{size}\t{}", self.path(id)))
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    fn walk_dirs(&self, dir: NodeId, visit: &mut impl FnMut(NodeId)) {
+        for &child in self.children(dir) {
+            if self.nodes[child].is_dir() {
+                self.walk_dirs(child, visit);
+            }
+        }
+        visit(dir);
+    }
+
+    /// `from` and every node under it whose size passes `filter`, in the order
+    /// of the tree.
+    pub fn find(&self, from: NodeId, filter: &SizeFilter) -> Vec<NodeId> {
+        let sizes = self.sizes();
+        let mut found = vec![];
+        let mut stack = vec![from];
+        while let Some(id) = stack.pop() {
+            if filter.matches(sizes[id]) {
+                found.push(id);
+            }
+            stack.extend(self.children(id).iter().rev());
+        }
+        found
+    }
+
+    /// Every node, indented by depth under its directory, like `- a (dir)`.
+    pub fn tree(&self) -> String {
+        let mut lines = vec![];
+        self.tree_lines(Self::ROOT, 0, &mut lines);
+        lines.join("\n")
+    }
+
+    fn tree_lines(&self, id: NodeId, depth: usize, lines: &mut Vec<String>) {
+        let node = &self.nodes[id];
+        let indent = "  ".repeat(depth);
+        match node.kind {
+            Kind::Dir { ref children } => {
+                lines.push(format!("{indent}- {} (dir)", node.name));
+                for &child in children {
+                    self.tree_lines(child, depth + 1, lines);
+                }
+            }
+            Kind::File { size } => {
+                lines.push(format!("{indent}- {} (file, size={size})", node.name))
+            }
+        }
+    }
+
+    /// Creates the tree in `dir`, each file a sparse file of its size, so the
+    /// sizes can be checked against the OS.
+    pub fn materialize(&self, dir: &Path) -> io::Result<()> {
+        fs::create_dir_all(dir)?;
+        for &child in self.children(Self::ROOT) {
+            self.materialize_node(child, dir)?;
+        }
+        Ok(())
+    }
+
+    fn materialize_node(&self, id: NodeId, dir: &Path) -> io::Result<()> {
+        let node = &self.nodes[id];
+        // Anything else could point outside of `dir`.
+        if !is_plain_name(&node.name) {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!("{:?} isn't a plain file name", node.name),
+            ));
+        }
+        let path = dir.join(&node.name);
+        match node.kind {
+            Kind::Dir { ref children } => {
+                fs::create_dir(&path)?;
+                for &child in children {
+                    self.materialize_node(child, &path)?;
+                }
+            }
+            Kind::File { size } => File::create(&path)?.set_len(size as u64)?,
+        }
+        Ok(())
+    }
+
+    /// Materializes the tree in a fresh temporary directory named after `name`.
+    pub fn materialize_in_temp_dir(&self, name: &str) -> io::Result<PathBuf> {
+        let dir = env::temp_dir().join(format!("aoc-day7-{name}-{}", process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        self.materialize(&dir)?;
+        Ok(dir)
+    }
+}
+
+/// Whether `name` names an entry of a directory, not a path to elsewhere.
+pub fn is_plain_name(name: &str) -> bool {
+    !matches!(name, "" | "." | "..") && !name.contains('/')
+}
+
+/// Sizes `find -size` keeps, in bytes: `+N` for more than N, `-N` for less.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SizeFilter {
+    MoreThan(usize),
+    LessThan(usize),
+    Exactly(usize),
+}
+
+impl FromStr for SizeFilter {
+    type Err = ParseError;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        Ok(if let Some(size) = input.strip_prefix('+') {
+            Self::MoreThan(parse_number(input, size)?)
+        } else if let Some(size) = input.strip_prefix('-') {
+            Self::LessThan(parse_number(input, size)?)
+        } else {
+            Self::Exactly(parse_number(input, input)?)
+        })
+    }
+}
+
+impl SizeFilter {
+    pub fn matches(&self, size: usize) -> bool {
+        match *self {
+            Self::MoreThan(limit) => size > limit,
+            Self::LessThan(limit) => size < limit,
+            Self::Exactly(limit) => size == limit,
+        }
+    }
+}
+
+pub trait Command {
+    /// The working directory after the command.
+    fn run(&self, fs: &mut FileSystem, cwd: NodeId) -> Result<NodeId, ParseError>;
+
+    fn interpret_output(
+        &self,
+        _fs: &mut FileSystem,
+        _cwd: NodeId,
+        line: &str,
+    ) -> Result<(), ParseError> {
+        Err(ParseError::new(line, "a command"))
+    }
+}
+
+/// Splits `path` into the directory it is in, from `cwd`, and its name.
+fn parent_and_name<'a>(
+    fs: &FileSystem,
+    cwd: NodeId,
+    path: &'a str,
+) -> Result<(NodeId, &'a str), ParseError> {
+    let (parent, name) = match path.trim_end_matches('/').rsplit_once('/') {
+        Some(("", name)) => (Some(FileSystem::ROOT), name),
+        Some((parent, name)) => (fs.resolve(cwd, parent), name),
+        None => (Some(cwd), path),
+    };
+    parent
+        .filter(|&parent| fs.node(parent).is_dir())
+        .map(|parent| (parent, name))
+        .ok_or_else(|| ParseError::new(path, "a path in an existing directory"))
+}
+
+fn required(input: &str, expected: &str) -> Result<String, ParseError> {
+    if input.is_empty() {
+        return Err(ParseError::new(input, expected));
+    }
+    Ok(input.to_owned())
+}
+
+#[derive(Debug)]
+pub struct Cd {
+    pub dir: String,
+}
+
+impl FromStr for Cd {
+    type Err = ParseError;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        Ok(Self {
+            dir: required(input, "a directory")?,
+        })
+    }
+}
+
+impl Command for Cd {
+    fn run(&self, fs: &mut FileSystem, cwd: NodeId) -> Result<NodeId, ParseError> {
+        fs.resolve(cwd, &self.dir)
+            .filter(|&dir| fs.node(dir).is_dir())
+            .ok_or_else(|| ParseError::new(&self.dir, format!("a directory of {}", fs.path(cwd))))
+    }
+}
+
+pub struct Ls {}
+
+impl Command for Ls {
+    fn run(&self, _: &mut FileSystem, cwd: NodeId) -> Result<NodeId, ParseError> {
+        Ok(cwd)
+    }
+
+    fn interpret_output(
+        &self,
+        fs: &mut FileSystem,
+        cwd: NodeId,
+        line: &str,
+    ) -> Result<(), ParseError> {
+        let (left, name) = line
+            .split_once(' ')
+            .ok_or_else(|| ParseError::new(line, "a size or dir, and a name"))?;
+        if !is_plain_name(name) {
+            return Err(ParseError::new(name, "a file name").within(line, name));
+        }
+        let (id, kind) = if left == "dir" {
+            (fs.add_dir(cwd, name), Kind::Dir { children: vec![] })
+        } else {
+            let size = parse_number(line, left)?;
+            (fs.add_file(cwd, name, size), Kind::File { size })
+        };
+        // Listing a directory again must show the same.
+        let listed = &fs.node(id).kind;
+        let same = match (listed, &kind) {
+            (Kind::Dir { .. }, Kind::Dir { .. }) => true,
+            (listed, kind) => listed == kind,
+        };
+        if !same {
+            let before = match listed {
+                Kind::Dir { .. } => "dir".to_owned(),
+                Kind::File { size } => size.to_string(),
+            };
+            return Err(ParseError::new(left, format!("{before} as listed before")));
+        }
+        Ok(())
+    }
+}
+
+pub struct Pwd {}
+
+impl Command for Pwd {
+    fn run(&self, _: &mut FileSystem, cwd: NodeId) -> Result<NodeId, ParseError> {
+        Ok(cwd)
+    }
+
+    fn interpret_output(
+        &self,
+        fs: &mut FileSystem,
+        cwd: NodeId,
+        line: &str,
+    ) -> Result<(), ParseError> {
+        let path = fs.path(cwd);
+        if line != path {
+            return Err(ParseError::new(line, path));
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug)]
+pub struct Mkdir {
+    pub path: String,
+}
+
+impl FromStr for Mkdir {
+    type Err = ParseError;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        Ok(Self {
+            path: required(input, "a directory")?,
+        })
+    }
+}
+
+impl Command for Mkdir {
+    fn run(&self, fs: &mut FileSystem, cwd: NodeId) -> Result<NodeId, ParseError> {
+        let (parent, name) = parent_and_name(fs, cwd, &self.path)?;
+        if !is_plain_name(name) {
+            return Err(ParseError::new(&self.path, "a new directory's path"));
+        }
+        if fs.child(parent, name).is_some() {
+            return Err(ParseError::new(
+                &self.path,
+                "a directory that doesn't exist",
+            ));
+        }
+        fs.add_dir(parent, name);
+        Ok(cwd)
+    }
+}
+
+#[derive(Debug)]
+pub struct Rm {
+    pub path: String,
+    pub recursive: bool,
+}
+
+impl FromStr for Rm {
+    type Err = ParseError;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let (recursive, path) = match input.strip_prefix("-r ") {
+            Some(path) => (true, path),
+            None => (false, input),
+        };
+        Ok(Self {
+            path: required(path, "a file, or -r and a directory")
+                .map_err(|err| err.within(input, path))?,
+            recursive,
+        })
+    }
+}
+
+impl Command for Rm {
+    fn run(&self, fs: &mut FileSystem, cwd: NodeId) -> Result<NodeId, ParseError> {
+        let id = fs
+            .resolve(cwd, &self.path)
+            .filter(|&id| id != FileSystem::ROOT)
+            .ok_or_else(|| ParseError::new(&self.path, "a file or directory to remove"))?;
+        if fs.node(id).is_dir() && !self.recursive {
+            return Err(ParseError::new(&self.path, "a file, or -r and a directory"));
+        }
+        // The working directory can't be left out of the tree.
+        let mut dir = Some(cwd);
+        while let Some(inside) = dir {
+            if inside == id {
+                return Err(ParseError::new(
+                    &self.path,
+                    "something outside of the working directory",
+                ));
+            }
+            dir = fs.node(inside).parent;
+        }
+        fs.remove(id);
+        Ok(cwd)
+    }
+}
+
+/// `find [path] -size [+|-]N`, a directory weighing all it contains.
+#[derive(Debug)]
+pub struct Find {
+    pub path: String,
+    pub size: SizeFilter,
+}
+
+impl FromStr for Find {
+    type Err = ParseError;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let (path, size) = input
+            .split_once("-size ")
+            .ok_or_else(|| ParseError::new(input, "a path and -size"))?;
+        Ok(Self {
+            path: match path.trim_end() {
+                "" => ".".to_owned(),
+                path => path.to_owned(),
+            },
+            size: parse_within(input, size)?,
+        })
+    }
+}
+
+impl Command for Find {
+    fn run(&self, fs: &mut FileSystem, cwd: NodeId) -> Result<NodeId, ParseError> {
+        fs.resolve(cwd, &self.path)
+            .ok_or_else(|| ParseError::new(&self.path, "a file or directory"))?;
+        Ok(cwd)
+    }
+
+    fn interpret_output(
+        &self,
+        fs: &mut FileSystem,
+        cwd: NodeId,
+        line: &str,
+    ) -> Result<(), ParseError> {
+        let from = fs.resolve(cwd, &self.path).unwrap_or(cwd);
+        let found = fs.find(from, &self.size);
+        if !fs.resolve(cwd, line).is_some_and(|id| found.contains(&id)) {
+            return Err(ParseError::new(line, "a path find matches"));
+        }
+        Ok(())
+    }
+}
+
+/// The file system a terminal session explored.
+pub fn read_terminal_output(input: &str) -> Result<FileSystem, ParseError> {
+    let mut fs = FileSystem::default();
+    let mut cwd = FileSystem::ROOT;
+
+    let mut current_cmd: Option<Box<dyn Command>> = None;
+
+    for line in input.lines() {
+        match line.strip_prefix("$ ") {
+            Some(command) => {
+                let (name, arg) = command
+                    .split_once(' ')
+                    .unwrap_or((command, &command[command.len()..]));
+                let cmd: Box<dyn Command> = match name {
+                    "cd" => Box::new(parse_within::<Cd>(input, arg)?),
+                    "ls" => Box::new(Ls {}),
+                    "pwd" => Box::new(Pwd {}),
+                    "mkdir" => Box::new(parse_within::<Mkdir>(input, arg)?),
+                    "rm" => Box::new(parse_within::<Rm>(input, arg)?),
+                    "find" => Box::new(parse_within::<Find>(input, arg)?),
+                    _ => {
+                        return Err(ParseError::new(name, "cd, ls, pwd, mkdir, rm or find")
+                            .within(input, name))
+                    }
+                };
+                cwd = cmd
+                    .run(&mut fs, cwd)
+                    .map_err(|err| err.within(input, arg))?;
+                current_cmd = Some(cmd);
+            }
+            None => match &current_cmd {
+                Some(cmd) => cmd
+                    .interpret_output(&mut fs, cwd, line)
+                    .map_err(|err| err.within(input, line))?,
+                None => return Err(ParseError::new(line, "a command").within(input, line)),
+            },
+        }
+    }
+
+    Ok(fs)
+}
+
+pub const TOTAL_SPACE: usize = 70000000;
+pub const NEEDED_SPACE: usize = 30000000;
+
+/// The sizes of the directories of at most `max`, summed.
+pub fn sum_of_dir_sizes(fs: &FileSystem, max: usize) -> usize {
+    fs.dir_sizes()
+        .into_iter()
+        .map(|(_, size)| size)
+        .filter(|&size| size <= max)
+        .sum()
+}
+
+/// The size of the smallest directory to delete to have the space needed.
+pub fn smallest_dir_to_delete(fs: &FileSystem) -> anyhow::Result<usize> {
+    let used = fs.size(FileSystem::ROOT);
+    let free = TOTAL_SPACE
+        .checked_sub(used)
+        .with_context(|| format!("{used} used, more than the disk's {TOTAL_SPACE}"))?;
+    let to_free = NEEDED_SPACE.saturating_sub(free);
+    fs.dir_sizes()
+        .into_iter()
+        .map(|(_, size)| size)
+        .filter(|&size| size >= to_free)
+        .min()
+        .context("no directory is big enough")
+}
+
+pub struct Day7;
+
+impl Solution for Day7 {
+    type Input = FileSystem;
+
+    fn parse(input: &str) -> anyhow::Result<Self::Input> {
+        Ok(read_terminal_output(input)?)
+    }
+
+    fn part1(fs: &Self::Input) -> anyhow::Result<Answer> {
+        Ok(sum_of_dir_sizes(fs, 100000).into())
+    }
+
+    fn part2(fs: &Self::Input) -> anyhow::Result<Answer> {
+        Ok(smallest_dir_to_delete(fs)?.into())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::{fs, path::Path};
+
+    use super::{read_terminal_output, smallest_dir_to_delete, sum_of_dir_sizes, FileSystem};
+
+    #[test]
+    fn example() {
+        let fs = read_terminal_output(include_str!("example")).unwrap();
+        assert_eq!(sum_of_dir_sizes(&fs, 100000), 95437)
+    }
+
+    #[test]
+    fn part2() {
+        let fs = read_terminal_output(include_str!("example")).unwrap();
+        assert_eq!(fs.size(FileSystem::ROOT), 48381165);
+        assert_eq!(smallest_dir_to_delete(&fs).unwrap(), 24933642);
+
+        let fs = read_terminal_output("$ cd /\n$ ls\n80000000 big\n").unwrap();
+        assert_eq!(
+            smallest_dir_to_delete(&fs).unwrap_err().to_string(),
+            "80000000 used, more than the disk's 70000000"
+        );
+    }
+
+    #[test]
+    fn tree() {
+        let fs = read_terminal_output(include_str!("example")).unwrap();
+        assert_eq!(fs.tree(), include_str!("expected"));
+    }
+
+    #[test]
+    fn paths() {
+        let fs = read_terminal_output(include_str!("example")).unwrap();
+
+        let e = fs.lookup("/a/e").unwrap();
+        assert_eq!(fs.path(e), "/a/e");
+        assert_eq!(fs.size(e), 584);
+        assert_eq!(fs.lookup("/"), Some(FileSystem::ROOT));
+        assert_eq!(fs.lookup("/a/x"), None);
+        assert_eq!(fs.lookup("a"), None);
+        assert_eq!(fs.resolve(e, "../../d/./k"), fs.lookup("/d/k"));
+
+        assert_eq!(fs.du(), "584\t/a/e\n94853\t/a\n24933642\t/d\n48381165\t/");
+    }
+
+    #[test]
+    fn shell() {
+        let fs = read_terminal_output(
+            "$ cd /\n$ ls\ndir a\n100 b.txt\n\
+             $ mkdir a/c\n$ cd a/c\n$ pwd\n/a/c\n$ ls\n20 x\n\
+             $ cd ../..\n$ ls\ndir a\n100 b.txt\n\
+             $ cd /a\n$ ls\ndir c\ndir d\n5 y\n$ rm -r d\n$ rm ../b.txt\n\
+             $ find / -size +20\n/\n/a\n$ find c -size 20\nc\nc/x\n",
+        )
+        .unwrap();
+        assert_eq!(
+            fs.tree(),
+            "- / (dir)\n  - a (dir)\n    - c (dir)\n      - x (file, size=20)\n    \
+             - y (file, size=5)"
+        );
+        assert_eq!(fs.du(), "20\t/a/c\n25\t/a\n25\t/");
+    }
+
+    /// The size of the files in `dir`, and under it.
+    fn disk_usage(dir: &Path) -> u64 {
+        fs::read_dir(dir)
+            .unwrap()
+            .map(|entry| {
+                let entry = entry.unwrap();
+                let metadata = entry.metadata().unwrap();
+                if metadata.is_dir() {
+                    disk_usage(&entry.path())
+                } else {
+                    metadata.len()
+                }
+            })
+            .sum()
+    }
+
+    #[test]
+    fn materialize() {
+        let tree = read_terminal_output(include_str!("example")).unwrap();
+        let dir = tree.materialize_in_temp_dir("example").unwrap();
+        for (id, size) in tree.dir_sizes() {
+            let path = dir.join(tree.path(id).trim_start_matches('/'));
+            assert_eq!(disk_usage(&path), size as u64, "{path:?}");
+        }
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn bad_output() {
+        let err = read_terminal_output("$ cd /\n$ dir a\n").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 2, column 3: expected cd, ls, pwd, mkdir, rm or find, found \"dir\""
+        );
+
+        let err = read_terminal_output("$ cd /\n$ ls\ndir a\n12k b.txt\n").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 4, column 1: expected a number, found \"12k\""
+        );
+
+        let err = read_terminal_output("$ cd /\n$ ls\ndir a\n$ cd b\n").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 4, column 6: expected a directory of /, found \"b\""
+        );
+    }
+
+    #[test]
+    fn bad_commands() {
+        let err = read_terminal_output("$ ls\n12 a\n$ ls\n13 a\n").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 4, column 1: expected 12 as listed before, found \"13\""
+        );
+
+        let err = read_terminal_output("$ mkdir a\n$ cd a\n$ pwd\n/b\n").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 4, column 1: expected /a, found \"/b\""
+        );
+
+        let err = read_terminal_output("$ mkdir a\n$ rm a\n").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 2, column 6: expected a file, or -r and a directory, found \"a\""
+        );
+
+        let err = read_terminal_output("$ ls\n3 a\n$ find -size +3\na\n").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 4, column 1: expected a path find matches, found \"a\""
+        );
+
+        let err = read_terminal_output("$ find -size x\n").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 1, column 14: expected a number, found \"x\""
+        );
+    }
+
+    #[test]
+    fn hostile_names() {
+        for name in ["/home/u/.bashrc", "..", ".", "a/b"] {
+            let err = read_terminal_output(&format!("$ ls\n100 {name}\n")).unwrap_err();
+            assert_eq!(
+                err.to_string(),
+                format!("line 2, column 5: expected a file name, found {name:?}")
+            );
+        }
+        let err = read_terminal_output("$ ls\ndir ..\n").unwrap_err();
+        assert_eq!(err.expected, "a file name");
+        let err = read_terminal_output("$ mkdir ..\n").unwrap_err();
+        assert_eq!(err.expected, "a new directory's path");
+
+        // Nor can a tree built by hand be written outside of its directory.
+        let mut tree = FileSystem::default();
+        tree.add_file(FileSystem::ROOT, "../escaped", 100);
+        let dir = std::env::temp_dir().join(format!("aoc-day7-hostile-{}", std::process::id()));
+        assert!(tree.materialize(&dir.join("root")).is_err());
+        assert!(!dir.join("escaped").exists());
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
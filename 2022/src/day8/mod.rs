@@ -0,0 +1,545 @@
+use std::{cmp::Reverse, iter, str::FromStr};
+
+use aoc_utils::{Answer, Direction, Grid, ParseError, Part, Pos, Solution};
+
+#[derive(Debug, Default)]
+pub struct Tree {
+    pub height: usize,
+    pub pos: Pos,
+}
+
+/// What a tree sees looking one way.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct View {
+    /// How many trees it sees, up to the first as high as itself.
+    pub distance: usize,
+    /// Whether it can be seen from the edge, all trees that way being lower.
+    pub visible: bool,
+}
+
+impl Tree {
+    pub fn is_visible_from(&self, direction: Direction, map: &Map) -> bool {
+        map.view(self.pos, direction).visible
+    }
+
+    pub fn is_visible(&self, map: &Map) -> bool {
+        Direction::ALL
+            .into_iter()
+            .any(|direction| self.is_visible_from(direction, map))
+    }
+
+    pub fn viewing_distance_to(&self, direction: Direction, map: &Map) -> usize {
+        map.view(self.pos, direction).distance
+    }
+
+    pub fn scenic_score(&self, map: &Map) -> usize {
+        Direction::ALL
+            .into_iter()
+            .map(|direction| self.viewing_distance_to(direction, map))
+            .product()
+    }
+}
+
+#[derive(Debug)]
+pub struct Map {
+    pub trees: Grid<Tree>,
+    /// What every tree sees, for each direction in the order of
+    /// [`Direction::ALL`].
+    views: [Grid<View>; 4],
+}
+
+impl FromStr for Map {
+    type Err = ParseError;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let heights = Grid::from_chars(input, "a height, from 0 to 9", |c| c.to_digit(10))?;
+        let trees = heights
+            .iter()
+            .map(|(pos, &height)| Tree {
+                height: height as usize,
+                pos,
+            })
+            .collect();
+        Ok(Self::new(Grid::new(heights.width(), trees)))
+    }
+}
+
+impl Map {
+    pub fn new(trees: Grid<Tree>) -> Self {
+        let views = Direction::ALL.map(|direction| Self::look(&trees, direction));
+        Self { trees, views }
+    }
+
+    /// What every tree sees looking towards `direction`, sweeping each line of
+    /// trees from that edge with a stack of the trees still in sight, highest
+    /// at the bottom.
+    fn look(trees: &Grid<Tree>, direction: Direction) -> Grid<View> {
+        let (width, height) = (trees.width(), trees.height());
+        let mut views = Grid::filled(width, height, View::default());
+        if width == 0 || height == 0 {
+            return views;
+        }
+        let edge: Vec<Pos> = match direction {
+            Direction::Up => (0..width).map(|x| Pos::new(x, 0)).collect(),
+            Direction::Down => (0..width).map(|x| Pos::new(x, height - 1)).collect(),
+            Direction::Left => (0..height).map(|y| Pos::new(0, y)).collect(),
+            Direction::Right => (0..height).map(|y| Pos::new(width - 1, y)).collect(),
+        };
+        let mut in_sight: Vec<(usize, usize)> = vec![];
+        for start in edge {
+            in_sight.clear();
+            let line = iter::once(start).chain(trees.ray(start, direction.opposite().step()));
+            for (i, pos) in line.enumerate() {
+                let tree_height = trees[pos].height;
+                while in_sight.last().is_some_and(|&(_, h)| h < tree_height) {
+                    in_sight.pop();
+                }
+                views[pos] = match in_sight.last() {
+                    Some(&(blocker, _)) => View {
+                        distance: i - blocker,
+                        visible: false,
+                    },
+                    None => View {
+                        distance: i,
+                        visible: true,
+                    },
+                };
+                in_sight.push((i, tree_height));
+            }
+        }
+        views
+    }
+
+    pub fn view(&self, pos: Pos, direction: Direction) -> View {
+        self.views[direction as usize][pos]
+    }
+
+    pub fn get_tree(&self, x: usize, y: usize, direction: Option<Direction>) -> Option<&Tree> {
+        let pos = match direction {
+            Some(direction) => self.trees.step(Pos::new(x, y), direction.step())?,
+            None => Pos::new(x, y),
+        };
+        self.trees.get(pos)
+    }
+
+    /// Whether each tree can be seen from outside the map.
+    pub fn visibility(&self) -> Grid<bool> {
+        let mut visibility = Grid::filled(self.trees.width(), self.trees.height(), false);
+        for views in &self.views {
+            for (pos, view) in views.iter() {
+                visibility[pos] |= view.visible;
+            }
+        }
+        visibility
+    }
+
+    /// The scenic score of each tree.
+    pub fn scenic_scores(&self) -> Grid<usize> {
+        let mut scores = Grid::filled(self.trees.width(), self.trees.height(), 1);
+        for views in &self.views {
+            for (pos, view) in views.iter() {
+                scores[pos] *= view.distance;
+            }
+        }
+        scores
+    }
+
+    pub fn visible_trees(&self) -> Vec<&Tree> {
+        let visibility = self.visibility();
+        self.trees
+            .iter()
+            .filter(|&(pos, _)| visibility[pos])
+            .map(|(_, tree)| tree)
+            .collect()
+    }
+
+    /// The tree with the highest scenic score, the first row by row if several.
+    pub fn best_tree(&self) -> Option<&Tree> {
+        let scores = self.scenic_scores();
+        let (pos, _) = scores.iter().min_by_key(|&(_, &score)| Reverse(score))?;
+        Some(&self.trees[pos])
+    }
+
+    pub fn highest_scenic_score(&self) -> usize {
+        self.best_tree()
+            .map(|tree| tree.scenic_score(self))
+            .unwrap_or_default()
+    }
+
+    /// The colour of each tree in `layer`, the best tree standing out.
+    fn colours(&self, layer: Layer) -> Grid<[u8; 3]> {
+        let best = self.best_tree().map(|tree| tree.pos);
+        let mut colours = match layer {
+            Layer::Visibility => self
+                .visibility()
+                .map(|&visible| if visible { VISIBLE } else { HIDDEN }),
+            Layer::ScenicScore => {
+                let scores = self.scenic_scores();
+                let highest = self.highest_scenic_score().max(1);
+                scores.map(|&score| heat(score * 255 / highest))
+            }
+        };
+        if let Some(best) = best {
+            colours[best] = BEST;
+        }
+        colours
+    }
+
+    /// `layer` as a plain PPM image, a pixel per tree. Each row starts a line, and
+    /// is wrapped every 5 pixels to stay within the format's 70 characters.
+    pub fn to_ppm(&self, layer: Layer) -> String {
+        let colours = self.colours(layer);
+        let rows: Vec<String> = colours
+            .rows()
+            .flat_map(|row| row.chunks(5))
+            .map(|pixels| {
+                pixels
+                    .iter()
+                    .map(|[r, g, b]| format!("{r} {g} {b}"))
+                    .collect::<Vec<_>>()
+                    .join("  ")
+            })
+            .collect();
+        format!(
+            "P3\n{} {}\n255\n{}\n",
+            colours.width(),
+            colours.height(),
+            rows.join("\n")
+        )
+    }
+
+    /// The heights of the trees on the colours of `layer`, for a terminal.
+    pub fn to_ansi(&self, layer: Layer) -> String {
+        let colours = self.colours(layer);
+        self.trees
+            .rows()
+            .zip(colours.rows())
+            .map(|(trees, colours)| {
+                let row: String = trees
+                    .iter()
+                    .zip(colours)
+                    .map(|(tree, &[r, g, b])| {
+                        // Dark digits on light colours.
+                        let ink = if r as usize + g as usize + b as usize > 384 {
+                            0
+                        } else {
+                            255
+                        };
+                        format!(
+                            "\x1b[38;2;{ink};{ink};{ink};48;2;{r};{g};{b}m{}",
+                            tree.height
+                        )
+                    })
+                    .collect();
+                format!("{row}\x1b[0m")
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+/// What to show of a [`Map`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Layer {
+    /// Trees seen from outside the map, or not.
+    Visibility,
+    /// Scenic scores from black for 0 to yellow for the highest, through red.
+    ScenicScore,
+}
+
+const VISIBLE: [u8; 3] = [34, 139, 34];
+const HIDDEN: [u8; 3] = [40, 40, 40];
+const BEST: [u8; 3] = [0, 128, 255];
+
+/// The colour of `level`, from 0 to 255, from black to yellow through red.
+fn heat(level: usize) -> [u8; 3] {
+    let level = level.min(255) * 2;
+    [level.min(255) as u8, level.saturating_sub(255) as u8, 0]
+}
+
+pub struct Day8;
+
+impl Solution for Day8 {
+    type Input = Map;
+
+    fn parse(input: &str) -> anyhow::Result<Self::Input> {
+        Ok(input.parse()?)
+    }
+
+    fn part1(map: &Self::Input) -> anyhow::Result<Answer> {
+        Ok(map.visible_trees().len().into())
+    }
+
+    fn part2(map: &Self::Input) -> anyhow::Result<Answer> {
+        Ok(map.highest_scenic_score().into())
+    }
+
+    fn render(map: &Self::Input, part: Part) -> anyhow::Result<Option<String>> {
+        let layer = match part {
+            Part::One => Layer::Visibility,
+            Part::Two => Layer::ScenicScore,
+        };
+        Ok(Some(map.to_ansi(layer)))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use aoc_utils::{Direction, Grid, Pos};
+
+    use super::{Layer, Map, Tree};
+
+    #[test]
+    fn part1_1() {
+        let map: Map = include_str!("example").parse().unwrap();
+
+        // The top-left 5 is visible from the left
+        assert!(map
+            .get_tree(1, 1, None)
+            .unwrap()
+            .is_visible_from(Direction::Left, &map));
+    }
+
+    #[test]
+    fn part1_2() {
+        let map: Map = include_str!("example").parse().unwrap();
+
+        // The top-left 5 is visible from the top
+        assert!(map
+            .get_tree(1, 1, None)
+            .unwrap()
+            .is_visible_from(Direction::Up, &map));
+    }
+
+    #[test]
+    fn part1_3() {
+        let map: Map = include_str!("example").parse().unwrap();
+
+        // The top-left 5 is visible from the top
+        assert!(map
+            .get_tree(2, 1, None)
+            .unwrap()
+            .is_visible_from(Direction::Up, &map));
+    }
+
+    #[test]
+    fn part1_4() {
+        let map: Map = include_str!("example").parse().unwrap();
+
+        // The top-left 5 is visible from the right
+        assert!(map
+            .get_tree(2, 1, None)
+            .unwrap()
+            .is_visible_from(Direction::Right, &map));
+    }
+
+    #[test]
+    fn part1_5() {
+        let map: Map = include_str!("example").parse().unwrap();
+
+        // The top-right 1 is not visible from any direction
+        let tree = map.get_tree(3, 1, None).unwrap();
+        assert!(!tree.is_visible_from(Direction::Right, &map));
+        assert!(!tree.is_visible_from(Direction::Left, &map));
+        assert!(!tree.is_visible_from(Direction::Up, &map));
+        assert!(!tree.is_visible_from(Direction::Down, &map));
+    }
+
+    #[test]
+    fn part1_6() {
+        let map: Map = include_str!("example").parse().unwrap();
+
+        // The left-middle 5 is visible, but only from the right.
+        let tree = map.get_tree(1, 2, None).unwrap();
+        assert!(tree.is_visible_from(Direction::Right, &map));
+        assert!(!tree.is_visible_from(Direction::Left, &map));
+        assert!(!tree.is_visible_from(Direction::Up, &map));
+        assert!(!tree.is_visible_from(Direction::Down, &map));
+    }
+
+    #[test]
+    fn part1_7() {
+        let map: Map = include_str!("example").parse().unwrap();
+
+        // The center 3 is not visible from any direction
+        let tree = map.get_tree(2, 2, None).unwrap();
+        assert!(!tree.is_visible_from(Direction::Right, &map));
+        assert!(!tree.is_visible_from(Direction::Left, &map));
+        assert!(!tree.is_visible_from(Direction::Up, &map));
+        assert!(!tree.is_visible_from(Direction::Down, &map));
+    }
+
+    #[test]
+    fn part1_8() {
+        let map: Map = include_str!("example").parse().unwrap();
+
+        // The right-middle 3 is visible from the right.
+        let tree = map.get_tree(3, 2, None).unwrap();
+        assert!(tree.is_visible_from(Direction::Right, &map));
+        assert!(!tree.is_visible_from(Direction::Left, &map));
+        assert!(!tree.is_visible_from(Direction::Up, &map));
+        assert!(!tree.is_visible_from(Direction::Down, &map));
+    }
+
+    #[test]
+    fn part1_10() {
+        let map: Map = include_str!("example").parse().unwrap();
+
+        // In the bottom row, the middle 5 is visible,
+        let tree = map.get_tree(3, 2, None).unwrap();
+        assert!(tree.is_visible_from(Direction::Right, &map));
+        assert!(!tree.is_visible_from(Direction::Left, &map));
+        assert!(!tree.is_visible_from(Direction::Up, &map));
+        assert!(!tree.is_visible_from(Direction::Down, &map));
+    }
+
+    #[test]
+    fn part1_9() {
+        let map: Map = include_str!("example").parse().unwrap();
+        assert_eq!(map.visible_trees().len(), 21);
+    }
+
+    #[test]
+    fn part2_1() {
+        let map: Map = include_str!("example").parse().unwrap();
+
+        let tree = map.get_tree(2, 1, None).unwrap();
+
+        assert_eq!(tree.viewing_distance_to(Direction::Up, &map), 1);
+        assert_eq!(tree.viewing_distance_to(Direction::Left, &map), 1);
+        assert_eq!(tree.viewing_distance_to(Direction::Right, &map), 2);
+        assert_eq!(tree.viewing_distance_to(Direction::Down, &map), 2);
+
+        assert_eq!(tree.scenic_score(&map), 4);
+    }
+
+    #[test]
+    fn part2_2() {
+        let map: Map = include_str!("example").parse().unwrap();
+
+        let tree = map.get_tree(2, 3, None).unwrap();
+
+        assert_eq!(tree.viewing_distance_to(Direction::Up, &map), 2);
+        assert_eq!(tree.viewing_distance_to(Direction::Left, &map), 2);
+        assert_eq!(tree.viewing_distance_to(Direction::Down, &map), 1);
+        assert_eq!(tree.viewing_distance_to(Direction::Right, &map), 2);
+
+        assert_eq!(tree.scenic_score(&map), 8);
+        assert_eq!(map.highest_scenic_score(), 8);
+    }
+
+    #[test]
+    fn bad_map() {
+        let err = "303\n2a5\n".parse::<Map>().unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 2, column 2: expected a height, from 0 to 9, found \"a\""
+        );
+    }
+
+    #[test]
+    fn grids() {
+        let map: Map = include_str!("example").parse().unwrap();
+        assert_eq!(
+            map.visibility()
+                .map(|&visible| if visible { '#' } else { '.' })
+                .to_string(),
+            "#####\n###.#\n##.##\n#.#.#\n#####"
+        );
+        assert_eq!(map.scenic_scores()[Pos::new(2, 3)], 8);
+        assert_eq!(map.scenic_scores()[Pos::new(0, 2)], 0);
+    }
+
+    /// The trees up to the edge, looked at one by one.
+    fn naive(map: &Map, pos: Pos) -> (bool, usize) {
+        let height = map.trees[pos].height;
+        let mut visible = false;
+        let mut score = 1;
+        for direction in Direction::ALL {
+            let line: Vec<usize> = map
+                .trees
+                .ray(pos, direction.step())
+                .map(|pos| map.trees[pos].height)
+                .collect();
+            visible |= line.iter().all(|&h| h < height);
+            score *= line
+                .iter()
+                .position(|&h| h >= height)
+                .map_or(line.len(), |i| i + 1);
+        }
+        (visible, score)
+    }
+
+    #[test]
+    fn large_forest() {
+        let (width, height) = (300, 200);
+        let mut seed = 7usize;
+        let trees = (0..width * height)
+            .map(|i| {
+                seed = seed
+                    .wrapping_mul(6364136223846793005)
+                    .wrapping_add(1442695040888963407);
+                Tree {
+                    height: (seed >> 33) % 10,
+                    pos: Pos::new(i % width, i / width),
+                }
+            })
+            .collect();
+        let map = Map::new(Grid::new(width, trees));
+        let (visibility, scores) = (map.visibility(), map.scenic_scores());
+        for pos in [(0, 0), (1, 1), (150, 100), (299, 199), (42, 7), (250, 150)] {
+            let pos = Pos::from(pos);
+            assert_eq!((visibility[pos], scores[pos]), naive(&map, pos), "{pos:?}");
+        }
+        for y in (0..height).step_by(13) {
+            for x in (0..width).step_by(7) {
+                let pos = Pos::new(x, y);
+                assert_eq!((visibility[pos], scores[pos]), naive(&map, pos), "{pos:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn empty_forest() {
+        let map = Map::new(Grid::new(3, vec![]));
+        assert!(map.visible_trees().is_empty());
+        assert!(map.best_tree().is_none());
+        assert_eq!(map.highest_scenic_score(), 0);
+        assert_eq!(map.to_ppm(Layer::Visibility), "P3\n3 0\n255\n\n");
+    }
+
+    #[test]
+    fn images() {
+        let map: Map = include_str!("example").parse().unwrap();
+        assert_eq!(map.best_tree().unwrap().pos, Pos::new(2, 3));
+
+        let visibility = map.to_ppm(Layer::Visibility);
+        let mut lines = visibility.lines();
+        assert_eq!(
+            lines.by_ref().take(3).collect::<Vec<_>>(),
+            ["P3", "5 5", "255"]
+        );
+        assert_eq!(
+            lines.nth(3).unwrap(),
+            "34 139 34  40 40 40  0 128 255  40 40 40  34 139 34"
+        );
+
+        let heatmap = map.to_ppm(Layer::ScenicScore);
+        // 4 of 8 is halfway to yellow, so about red.
+        assert_eq!(
+            heatmap.lines().nth(4).unwrap(),
+            "0 0 0  62 0 0  254 0 0  62 0 0  0 0 0"
+        );
+
+        let ansi = map.to_ansi(Layer::ScenicScore);
+        assert_eq!(ansi.lines().count(), 5);
+        assert!(ansi.starts_with("\x1b[38;2;255;255;255;48;2;0;0;0m3"));
+        assert!(ansi.contains("\x1b[38;2;255;255;255;48;2;0;128;255m5"));
+        assert!(ansi.lines().all(|line| line.ends_with("\x1b[0m")));
+
+        let wide: Map = format!("{0}\n{0}\n", "5".repeat(99)).parse().unwrap();
+        let wide = wide.to_ppm(Layer::Visibility);
+        assert_eq!(wide.lines().count(), 3 + 2 * 20);
+        assert!(wide.lines().all(|line| line.len() <= 70));
+    }
+}
@@ -0,0 +1,343 @@
+use std::{collections::HashSet, str::FromStr};
+
+use aoc_utils::{
+    parse_lines, parse_number, parse_within, Answer, ParseError, Point2, Solution, Vec2,
+};
+
+#[derive(Debug)]
+pub enum Dir {
+    Right,
+    Up,
+    Left,
+    Down,
+}
+
+impl FromStr for Dir {
+    type Err = ParseError;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        match input {
+            "R" => Ok(Self::Right),
+            "U" => Ok(Self::Up),
+            "L" => Ok(Self::Left),
+            "D" => Ok(Self::Down),
+            _ => Err(ParseError::new(input, "R, U, L or D")),
+        }
+    }
+}
+
+impl Dir {
+    /// One step this way, `y` growing upwards.
+    pub fn step(&self) -> Vec2<isize> {
+        match self {
+            Dir::Right => Vec2::new(1, 0),
+            Dir::Up => Vec2::new(0, 1),
+            Dir::Left => Vec2::new(-1, 0),
+            Dir::Down => Vec2::new(0, -1),
+        }
+    }
+
+    pub fn apply(&self, board: &mut Board) {
+        let rope = &mut board.rope;
+        rope[0] += self.step();
+
+        for i in 1..rope.len() {
+            let gap = rope[i - 1] - rope[i];
+            if gap.chebyshev() > 1 {
+                rope[i] += gap.signum();
+            }
+        }
+
+        board.create_tail_square_if_needed();
+    }
+}
+
+#[derive(Debug)]
+pub struct Move {
+    pub dir: Dir,
+    pub length: usize,
+}
+
+impl Move {
+    pub fn apply(&self, board: &mut Board) {
+        (0..self.length).for_each(|_| self.dir.apply(board));
+    }
+}
+
+impl FromStr for Move {
+    type Err = ParseError;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let (dir, length) = input
+            .split_once(' ')
+            .ok_or_else(|| ParseError::new(input, "a direction and a length"))?;
+        Ok(Self {
+            dir: parse_within(input, dir)?,
+            length: parse_number(input, length)?,
+        })
+    }
+}
+
+#[derive(Default, Debug)]
+pub struct Board {
+    pub squares: HashSet<Point2<isize>>,
+    pub rope: Vec<Point2<isize>>,
+}
+
+impl Board {
+    pub fn new(length: usize) -> Board {
+        let mut squares = HashSet::new();
+        squares.insert(Point2::default());
+        Board {
+            rope: vec![Point2::default(); length],
+            squares,
+        }
+    }
+
+    pub fn create_tail_square_if_needed(&mut self) {
+        let tail = *self.rope.last().unwrap();
+        self.squares.insert(tail);
+    }
+
+    pub fn show(&self, size: isize) {
+        (0..size).rev().for_each(|row| {
+            (0..size).for_each(|col| {
+                match self
+                    .rope
+                    .iter()
+                    .enumerate()
+                    .find(|(_, &knot)| knot == Point2::new(col, row))
+                {
+                    None => print!("."),
+                    Some((i, _)) => {
+                        if i == 0 {
+                            print!("H");
+                        } else {
+                            print!("{}", i);
+                        }
+                    }
+                }
+            });
+            println!()
+        });
+        println!();
+    }
+
+    pub fn show_visited(&self) {
+        let x = self.squares.iter().map(|square| square.x).max().unwrap();
+        let y = self.squares.iter().map(|square| square.y).max().unwrap();
+
+        (0..y).rev().for_each(|row| {
+            (0..x).for_each(|col| {
+                if self.squares.contains(&Point2::new(col, row)) {
+                    print!("#");
+                } else {
+                    print!(".");
+                }
+            });
+            println!()
+        });
+        println!();
+    }
+}
+
+pub fn walk(moves: &[Move], board: &mut Board) {
+    moves.iter().for_each(|m| m.apply(board));
+}
+
+pub struct Day9;
+
+impl Solution for Day9 {
+    type Input = Vec<Move>;
+
+    fn parse(input: &str) -> anyhow::Result<Self::Input> {
+        Ok(parse_lines(input)?)
+    }
+
+    fn part1(input: &Self::Input) -> anyhow::Result<Answer> {
+        let mut board = Board::new(2);
+        walk(input, &mut board);
+        Ok(board.squares.len().into())
+    }
+
+    fn part2(input: &Self::Input) -> anyhow::Result<Answer> {
+        let mut board = Board::new(10);
+        walk(input, &mut board);
+        Ok(board.squares.len().into())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use aoc_utils::{parse_lines, Point2};
+
+    use super::{walk, Board, Dir, Move};
+
+    #[test]
+    fn part1() {
+        let moves: Vec<Move> = parse_lines(include_str!("example")).unwrap();
+        let mut board = Board::new(2);
+        board.show(6);
+        walk(&moves, &mut board);
+        board.show(6);
+        assert_eq!(board.squares.len(), 13);
+    }
+
+    #[test]
+    fn part2() {
+        let moves: Vec<Move> = parse_lines(include_str!("example_part2")).unwrap();
+        let mut board = Board::new(10);
+        walk(&moves, &mut board);
+        board.show(36);
+        assert_eq!(board.squares.len(), 36);
+    }
+
+    #[test]
+    fn part2_11() {
+        let mut board = Board::new(10);
+        board.rope = vec![
+            Point2::new(3, 4),
+            Point2::new(4, 3),
+            Point2::new(4, 2),
+            Point2::new(3, 2),
+            Point2::new(2, 2),
+            Point2::new(1, 1),
+            Point2::new(0, 0),
+            Point2::new(0, 0),
+            Point2::new(0, 0),
+            Point2::new(0, 0),
+        ];
+        board.show(6);
+        Dir::Left.apply(&mut board);
+        board.show(6);
+        assert_eq!(board.rope.len(), 10);
+        assert_eq!(board.rope.first().unwrap(), &Point2::new(2, 4));
+        assert_eq!(board.rope.get(1).unwrap(), &Point2::new(3, 4));
+        assert_eq!(board.rope.get(2).unwrap(), &Point2::new(3, 3));
+        assert_eq!(board.rope.get(3).unwrap(), &Point2::new(3, 2));
+        assert_eq!(board.rope.get(4).unwrap(), &Point2::new(2, 2));
+        assert_eq!(board.rope.get(5).unwrap(), &Point2::new(1, 1));
+        assert_eq!(board.rope.get(6).unwrap(), &Point2::new(0, 0));
+        assert_eq!(board.rope.get(7).unwrap(), &Point2::new(0, 0));
+        assert_eq!(board.rope.get(8).unwrap(), &Point2::new(0, 0));
+        assert_eq!(board.rope.get(9).unwrap(), &Point2::new(0, 0));
+    }
+
+    #[test]
+    fn part2_12() {
+        let mut board = Board::new(10);
+        board.rope = vec![
+            Point2::new(2, 4),
+            Point2::new(3, 4),
+            Point2::new(3, 3),
+            Point2::new(3, 2),
+            Point2::new(2, 2),
+            Point2::new(1, 1),
+            Point2::new(0, 0),
+            Point2::new(0, 0),
+            Point2::new(0, 0),
+            Point2::new(0, 0),
+        ];
+        board.show(27);
+        Dir::Left.apply(&mut board);
+        board.show(27);
+        assert_eq!(board.rope.len(), 10);
+        assert_eq!(board.rope.first().unwrap(), &Point2::new(1, 4));
+        assert_eq!(board.rope.get(1).unwrap(), &Point2::new(2, 4));
+        assert_eq!(board.rope.get(2).unwrap(), &Point2::new(3, 3));
+        assert_eq!(board.rope.get(3).unwrap(), &Point2::new(3, 2));
+        assert_eq!(board.rope.get(4).unwrap(), &Point2::new(2, 2));
+        assert_eq!(board.rope.get(5).unwrap(), &Point2::new(1, 1));
+        assert_eq!(board.rope.get(6).unwrap(), &Point2::new(0, 0));
+        assert_eq!(board.rope.get(7).unwrap(), &Point2::new(0, 0));
+        assert_eq!(board.rope.get(8).unwrap(), &Point2::new(0, 0));
+        assert_eq!(board.rope.get(9).unwrap(), &Point2::new(0, 0));
+    }
+
+    #[test]
+    fn part2_13() {
+        let mut board = Board::new(10);
+        board.rope = vec![
+            Point2::new(1, 4),
+            Point2::new(2, 4),
+            Point2::new(3, 3),
+            Point2::new(3, 2),
+            Point2::new(2, 2),
+            Point2::new(1, 1),
+            Point2::new(0, 0),
+            Point2::new(0, 0),
+            Point2::new(0, 0),
+            Point2::new(0, 0),
+        ];
+        board.show(6);
+        Dir::Down.apply(&mut board);
+        board.show(6);
+        assert_eq!(board.rope.len(), 10);
+        assert_eq!(board.rope.first().unwrap(), &Point2::new(1, 3));
+        assert_eq!(board.rope.get(1).unwrap(), &Point2::new(2, 4));
+        assert_eq!(board.rope.get(2).unwrap(), &Point2::new(3, 3));
+        assert_eq!(board.rope.get(3).unwrap(), &Point2::new(3, 2));
+        assert_eq!(board.rope.get(4).unwrap(), &Point2::new(2, 2));
+        assert_eq!(board.rope.get(5).unwrap(), &Point2::new(1, 1));
+        assert_eq!(board.rope.get(6).unwrap(), &Point2::new(0, 0));
+        assert_eq!(board.rope.get(7).unwrap(), &Point2::new(0, 0));
+        assert_eq!(board.rope.get(8).unwrap(), &Point2::new(0, 0));
+        assert_eq!(board.rope.get(9).unwrap(), &Point2::new(0, 0));
+    }
+
+    #[test]
+    fn part2_2_3() {
+        let mut board = Board::new(10);
+        board.rope = vec![
+            Point2::new(16, 5),
+            Point2::new(15, 5),
+            Point2::new(14, 5),
+            Point2::new(13, 5),
+            Point2::new(12, 5),
+            Point2::new(11, 5),
+            Point2::new(11, 5),
+            Point2::new(11, 5),
+            Point2::new(11, 5),
+            Point2::new(11, 5),
+        ];
+        board.show(20);
+        "U 8".parse::<Move>().unwrap().apply(&mut board);
+        board.show(20);
+        assert_eq!(board.rope.len(), 10);
+        assert_eq!(board.rope.first().unwrap(), &Point2::new(16, 13));
+        assert_eq!(board.rope.get(1).unwrap(), &Point2::new(16, 12));
+        assert_eq!(board.rope.get(2).unwrap(), &Point2::new(16, 11));
+        assert_eq!(board.rope.get(3).unwrap(), &Point2::new(16, 10));
+        assert_eq!(board.rope.get(4).unwrap(), &Point2::new(16, 9));
+        assert_eq!(board.rope.get(5).unwrap(), &Point2::new(15, 9));
+        assert_eq!(board.rope.get(6).unwrap(), &Point2::new(14, 8));
+        assert_eq!(board.rope.get(7).unwrap(), &Point2::new(13, 7));
+        assert_eq!(board.rope.get(8).unwrap(), &Point2::new(12, 6));
+        assert_eq!(board.rope.get(9).unwrap(), &Point2::new(11, 5));
+    }
+
+    #[test]
+    fn bad_moves() {
+        let err = parse_lines::<Move>(
+            "R 4
+X 2
+",
+        )
+        .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 2, column 1: expected R, U, L or D, found \"X\""
+        );
+
+        let err = parse_lines::<Move>(
+            "R 4
+U -2
+",
+        )
+        .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 2, column 3: expected a number, found \"-2\""
+        );
+    }
+}
@@ -1,19 +1,9 @@
 use anyhow::Context;
+use aoc_utils::{Answer, Solution};
 use core::str::Lines;
 use once_cell::sync::Lazy;
 use std::collections::HashMap;
 
-#[tokio::main]
-async fn main() -> anyhow::Result<()> {
-    let input = include_str!("inputs/part1").to_string();
-    println!("{}", input.lines().calibration_value()?);
-
-    let input = include_str!("inputs/part2").to_string();
-    println!("{}", input.lines().calibration_value()?);
-
-    Ok(())
-}
-
 //const NUMBERS: [&str; 20] = [
 //    "0", "1", "2", "3", "4", "5", "6", "7", "8", "9", "zero", "one", "two", "three", "four",
 //    "five", "six", "seven", "eight", "nine",
@@ -58,7 +48,7 @@ impl Calibrator for &str {
             .filter(|(_, pos)| pos.is_some())
             .collect();
 
-        numbers_first_positions.sort_by(|(_, pos1), (_, pos2)| pos1.cmp(pos2));
+        numbers_first_positions.sort_by_key(|(_, pos)| *pos);
         let first_number = numbers_first_positions
             .first()
             .context("no numbers found")?;
@@ -69,7 +59,7 @@ impl Calibrator for &str {
             .filter(|(_, pos)| pos.is_some())
             .collect();
 
-        numbers_last_positions.sort_by(|(_, pos1), (_, pos2)| pos1.cmp(pos2));
+        numbers_last_positions.sort_by_key(|(_, pos)| *pos);
         let last_number = numbers_last_positions.last().context("no numbers found")?;
 
         Ok(NUMBERS.get(first_number.0).context("invalid number")? * 10
@@ -87,6 +77,20 @@ impl Calibrator for Lines<'_> {
     }
 }
 
+pub struct Day1;
+
+impl Solution for Day1 {
+    type Input = String;
+
+    fn parse(input: &str) -> anyhow::Result<Self::Input> {
+        Ok(input.to_string())
+    }
+
+    fn part2(input: &Self::Input) -> anyhow::Result<Answer> {
+        Ok(input.lines().calibration_value()?.into())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::Calibrator;
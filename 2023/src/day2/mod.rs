@@ -1,6 +1,7 @@
 use std::collections::HashMap;
 
 use anyhow::Context;
+use aoc_utils::{Answer, Solution};
 use once_cell::sync::Lazy;
 
 static CONFIGURATION: Lazy<HashMap<&str, usize>> = Lazy::new(|| {
@@ -133,12 +134,22 @@ pub fn power(input: &str) -> anyhow::Result<usize> {
     Ok(power)
 }
 
-fn main() -> anyhow::Result<()> {
-    let input = include_str!("inputs/part1");
-    println!("part1: {}", sum_of_ids_of_possible_games(input)?);
-    println!("part2: {}", power(input)?);
+pub struct Day2;
 
-    Ok(())
+impl Solution for Day2 {
+    type Input = String;
+
+    fn parse(input: &str) -> anyhow::Result<Self::Input> {
+        Ok(input.to_string())
+    }
+
+    fn part1(input: &Self::Input) -> anyhow::Result<Answer> {
+        Ok(sum_of_ids_of_possible_games(input)?.into())
+    }
+
+    fn part2(input: &Self::Input) -> anyhow::Result<Answer> {
+        Ok(power(input)?.into())
+    }
 }
 
 #[cfg(test)]
@@ -0,0 +1,132 @@
+use std::ops::Range;
+
+use aoc_utils::{Answer, Grid, Point2, Pos, Solution};
+
+/// A number of the engine schematic, written at `xs` on row `y`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Number {
+    pub value: u64,
+    pub y: usize,
+    pub xs: Range<usize>,
+}
+
+impl Number {
+    pub fn cells(&self) -> impl Iterator<Item = Pos> + '_ {
+        self.xs.clone().map(|x| Point2::new(x, self.y))
+    }
+
+    /// Whether `pos` is next to one of its digits, diagonally included.
+    pub fn is_next_to(&self, pos: Pos) -> bool {
+        self.cells().any(|cell| cell.chebyshev(pos) == 1)
+    }
+}
+
+pub fn is_symbol(c: char) -> bool {
+    !c.is_ascii_digit() && c != '.'
+}
+
+/// Every number of `schematic`, row by row.
+pub fn all_numbers(schematic: &Grid<char>) -> Vec<Number> {
+    let mut result = vec![];
+
+    for (y, row) in schematic.rows().enumerate() {
+        let mut x = 0;
+        while x < row.len() {
+            let start = x;
+            let mut value = 0;
+            while let Some(digit) = row.get(x).and_then(|c| c.to_digit(10)) {
+                value = value * 10 + digit as u64;
+                x += 1;
+            }
+            if x > start {
+                result.push(Number {
+                    value,
+                    y,
+                    xs: start..x,
+                });
+            } else {
+                x += 1;
+            }
+        }
+    }
+
+    result
+}
+
+/// The part numbers: the numbers next to a symbol.
+pub fn numbers(schematic: &Grid<char>) -> Vec<u64> {
+    all_numbers(schematic)
+        .into_iter()
+        .filter(|number| {
+            number
+                .cells()
+                .flat_map(|cell| schematic.neighbours_diagonal(cell))
+                .any(|pos| is_symbol(schematic[pos]))
+        })
+        .map(|number| number.value)
+        .collect()
+}
+
+/// The products of the two numbers next to each `*` that has exactly two.
+pub fn gear_ratios(schematic: &Grid<char>) -> Vec<u64> {
+    let numbers = all_numbers(schematic);
+
+    schematic
+        .iter()
+        .filter(|(_, &c)| c == '*')
+        .filter_map(|(star, _)| {
+            let around: Vec<u64> = numbers
+                .iter()
+                .filter(|number| number.is_next_to(star))
+                .map(|number| number.value)
+                .collect();
+            (around.len() == 2).then(|| around[0] * around[1])
+        })
+        .collect()
+}
+
+pub struct Day3;
+
+impl Solution for Day3 {
+    type Input = Grid<char>;
+
+    fn parse(input: &str) -> anyhow::Result<Self::Input> {
+        Ok(Grid::from_chars(input, "a character", Some)?)
+    }
+
+    fn part1(schematic: &Self::Input) -> anyhow::Result<Answer> {
+        Ok(numbers(schematic).iter().sum::<u64>().into())
+    }
+
+    fn part2(schematic: &Self::Input) -> anyhow::Result<Answer> {
+        Ok(gear_ratios(schematic).iter().sum::<u64>().into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_utils::Solution;
+
+    use super::{numbers, Day3};
+
+    #[test]
+    fn test_numbers() {
+        let schematic = Day3::parse(include_str!("samples/part1")).unwrap();
+
+        let numbers = numbers(&schematic);
+
+        assert_eq!(numbers, vec![467, 35, 633, 617, 592, 755, 664, 598]);
+
+        let total: u64 = numbers.iter().sum();
+        assert_eq!(total, 4361);
+    }
+
+    #[test]
+    fn test_gear_ratios() {
+        let schematic = Day3::parse(include_str!("samples/part1")).unwrap();
+
+        let gear_ratios = super::gear_ratios(&schematic);
+
+        assert_eq!(gear_ratios, vec![16345, 451490]);
+    }
+}
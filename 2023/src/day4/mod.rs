@@ -0,0 +1,110 @@
+use std::str::FromStr;
+
+use aoc_utils::{numbers, parse_lines, parse_within, Answer, ParseError, Solution};
+
+#[derive(Debug)]
+pub struct Numbers(Vec<u64>);
+
+impl FromStr for Numbers {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(Self(numbers(s).collect::<Result<_, _>>()?))
+    }
+}
+
+#[derive(Debug)]
+pub struct Card {
+    pub numbers_you_have: Numbers,
+    pub winning_numbers: Numbers,
+}
+
+impl FromStr for Card {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (_, numbers) = s
+            .split_once(':')
+            .ok_or_else(|| ParseError::new(s, "a card like `Card 1: 41 | 83`"))?;
+        let (numbers_you_have, winning_numbers) = numbers.split_once('|').ok_or_else(|| {
+            ParseError::new(numbers, "numbers, `|` and numbers").within(s, numbers)
+        })?;
+        Ok(Self {
+            numbers_you_have: parse_within(s, numbers_you_have)?,
+            winning_numbers: parse_within(s, winning_numbers)?,
+        })
+    }
+}
+
+#[derive(Debug)]
+pub struct Cards(Vec<Card>);
+
+impl FromStr for Cards {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(Self(parse_lines(s)?))
+    }
+}
+
+impl Cards {
+    pub fn score(&self) -> u64 {
+        self.0.iter().map(|card| card.score()).sum()
+    }
+}
+
+impl Card {
+    fn matches(&self) -> Vec<u64> {
+        let mut matches = vec![];
+        for number in self.numbers_you_have.0.iter() {
+            if self.winning_numbers.0.contains(number) {
+                matches.push(*number);
+            }
+        }
+        matches
+    }
+
+    pub fn score(&self) -> u64 {
+        let length = self.matches().len();
+        if length == 0 {
+            return 0;
+        }
+        u64::pow(2, (self.matches().len() - 1) as u32)
+    }
+}
+
+pub struct Day4;
+
+impl Solution for Day4 {
+    type Input = Cards;
+
+    fn parse(input: &str) -> anyhow::Result<Self::Input> {
+        Ok(input.parse()?)
+    }
+
+    fn part1(cards: &Self::Input) -> anyhow::Result<Answer> {
+        Ok(cards.score().into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Cards;
+
+    #[test]
+    fn test_score() {
+        let cards: Cards = include_str!("samples/part1").parse().unwrap();
+        assert_eq!(cards.score(), 13);
+    }
+
+    #[test]
+    fn bad_cards() {
+        let err = "Card 1: 41 48 | 83 86\nCard 2: 13 32 | 61 300000000000000000000\n"
+            .parse::<Cards>()
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 2, column 20: expected a number, found \"300000000000000000000\""
+        );
+    }
+}
@@ -0,0 +1,19 @@
+use aoc_utils::Registry;
+
+pub mod day1;
+pub mod day2;
+pub mod day3;
+pub mod day4;
+
+pub const YEAR: u16 = 2023;
+
+pub fn register(registry: &mut Registry) {
+    registry
+        .register::<day1::Day1>(YEAR, 1)
+        .register::<day2::Day2>(YEAR, 2)
+        .register::<day3::Day3>(YEAR, 3)
+        .register::<day4::Day4>(YEAR, 4);
+}
+
+/// Where the inputs shipped with the repository live, one `dayN` directory per day.
+pub const INPUTS: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/src");
@@ -0,0 +1,21 @@
+//! One test per line of `answers`, generated by `build.rs`.
+
+use std::path::Path;
+
+use aoc_utils::{
+    answers::{self, Expected},
+    Part, Registry,
+};
+
+fn check(expected: &Expected) {
+    let mut registry = Registry::new();
+    aoc2023::register(&mut registry);
+    answers::check(
+        &registry,
+        aoc2023::YEAR,
+        Path::new(aoc2023::INPUTS),
+        expected,
+    );
+}
+
+include!(concat!(env!("OUT_DIR"), "/answers.rs"));
@@ -0,0 +1,250 @@
+use std::{
+    collections::BTreeMap,
+    fs,
+    path::Path,
+    time::{Duration, Instant},
+};
+
+use anyhow::{bail, Context};
+use aoc_utils::{Day, InputSource, Part, Unsolved};
+use serde::{Deserialize, Serialize};
+
+/// Mean times of a day, in nanoseconds; `None` for the parts it doesn't solve.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Timings {
+    pub parse: u64,
+    pub part1: Option<u64>,
+    pub part2: Option<u64>,
+}
+
+impl Timings {
+    fn cells(&self) -> [Option<u64>; 3] {
+        [Some(self.parse), self.part1, self.part2]
+    }
+}
+
+/// What `aoc bench --save` writes and `aoc bench --compare` reads.
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub struct Baseline {
+    pub year: u16,
+    pub iterations: u32,
+    pub days: BTreeMap<u8, Timings>,
+}
+
+impl Baseline {
+    pub fn load(path: &Path) -> anyhow::Result<Self> {
+        let json =
+            fs::read_to_string(path).with_context(|| format!("can't read {}", path.display()))?;
+        serde_json::from_str(&json).with_context(|| format!("bad baseline {}", path.display()))
+    }
+
+    /// Loads the baseline at `path` to compare `year` against.
+    pub fn load_for(path: &Path, year: u16) -> anyhow::Result<Self> {
+        let baseline = Self::load(path)?;
+        if baseline.year != year {
+            bail!(
+                "{} is a baseline of {}, not {year}",
+                path.display(),
+                baseline.year
+            );
+        }
+        Ok(baseline)
+    }
+
+    pub fn save(&self, path: &Path) -> anyhow::Result<()> {
+        fs::write(path, serde_json::to_string_pretty(self)?)
+            .with_context(|| format!("can't write {}", path.display()))
+    }
+}
+
+fn timed<T>(f: impl FnOnce() -> T) -> (T, Duration) {
+    let start = Instant::now();
+    let result = f();
+    (result, start.elapsed())
+}
+
+/// Parses and solves both parts of `day` `iterations` times.
+pub fn bench(day: &Day, source: &InputSource, iterations: u32) -> anyhow::Result<Timings> {
+    let input1 = source.read(day.day, Part::One)?;
+    let input2 = source.read(day.day, Part::Two)?;
+
+    let mut parse = Duration::ZERO;
+    let mut parts = [Some(Duration::ZERO), Some(Duration::ZERO)];
+    for _ in 0..iterations {
+        let (puzzle1, elapsed) = timed(|| day.parse(&input1));
+        let puzzle1 = puzzle1?;
+        parse += elapsed;
+        let puzzle2 = match input2 == input1 {
+            true => None,
+            false => {
+                let (puzzle2, elapsed) = timed(|| day.parse(&input2));
+                parse += elapsed;
+                Some(puzzle2?)
+            }
+        };
+
+        for (part, total) in [Part::One, Part::Two].into_iter().zip(&mut parts) {
+            let Some(elapsed_total) = total else {
+                continue;
+            };
+            let puzzle = match part {
+                Part::One => &puzzle1,
+                Part::Two => puzzle2.as_ref().unwrap_or(&puzzle1),
+            };
+            match timed(|| puzzle.solve(part)) {
+                (Ok(_), elapsed) => *elapsed_total += elapsed,
+                (Err(err), _) if err.is::<Unsolved>() => *total = None,
+                (Err(err), _) => return Err(err.context(format!("day {} {part}", day.day))),
+            }
+        }
+    }
+
+    let mean = |total: Duration| (total / iterations).as_nanos() as u64;
+    Ok(Timings {
+        parse: mean(parse),
+        part1: parts[0].map(mean),
+        part2: parts[1].map(mean),
+    })
+}
+
+/// How much slower, in percent, every timing is compared to `baseline`.
+fn changes(baseline: &Timings, current: &Timings) -> [Option<f64>; 3] {
+    let mut changes = [None; 3];
+    for ((change, before), after) in changes
+        .iter_mut()
+        .zip(baseline.cells())
+        .zip(current.cells())
+    {
+        if let (Some(before), Some(after)) = (before, after) {
+            *change = Some((after as f64 - before as f64) * 100.0 / before.max(1) as f64);
+        }
+    }
+    changes
+}
+
+/// The table of `days`, with the change against `baseline` if there is one, and
+/// how many timings got more than `threshold` percent slower.
+pub fn table(
+    days: &BTreeMap<u8, Timings>,
+    baseline: Option<&Baseline>,
+    threshold: f64,
+) -> (String, usize) {
+    let mut regressions = 0;
+    let mut table = format!(
+        "{:>5}{:>22}{:>22}{:>22}\n",
+        "day", "parse", "part1", "part2"
+    );
+
+    for (day, timings) in days {
+        let before = baseline.and_then(|baseline| baseline.days.get(day));
+        let changes = before.map(|before| changes(before, timings));
+
+        table += &format!("{day:>5}");
+        for (i, cell) in timings.cells().into_iter().enumerate() {
+            let mut cell = match cell {
+                Some(nanos) => format!("{:.1?}", Duration::from_nanos(nanos)),
+                None => "-".to_string(),
+            };
+            if let Some(change) = changes.and_then(|changes| changes[i]) {
+                let flag = if change > threshold {
+                    regressions += 1;
+                    "!"
+                } else {
+                    " "
+                };
+                cell += &format!(" ({change:+.0}%){flag}");
+            }
+            table += &format!("{cell:>22}");
+        }
+        table += "\n";
+    }
+
+    (table, regressions)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{collections::BTreeMap, fs};
+
+    use aoc_utils::{Answer, InputSource, Registry, Solution};
+
+    use super::{bench, table, Baseline, Timings};
+    use crate::test_server::temp_dir;
+
+    struct Lines;
+
+    impl Solution for Lines {
+        type Input = usize;
+
+        fn parse(input: &str) -> anyhow::Result<Self::Input> {
+            Ok(input.lines().count())
+        }
+
+        fn part1(lines: &Self::Input) -> anyhow::Result<Answer> {
+            Ok((*lines).into())
+        }
+    }
+
+    #[test]
+    fn unsolved_parts_are_not_timed() {
+        let mut registry = Registry::new();
+        registry.register::<Lines>(2022, 1);
+        let source = InputSource::Text("a\nb\n".to_string());
+
+        let timings = bench(registry.get(2022, 1).unwrap(), &source, 3).unwrap();
+        assert!(timings.part1.is_some());
+        assert_eq!(timings.part2, None);
+    }
+
+    #[test]
+    fn regressions() {
+        let timings = |parse, part1| Timings {
+            parse,
+            part1: Some(part1),
+            part2: None,
+        };
+        let baseline = Baseline {
+            year: 2022,
+            iterations: 10,
+            days: BTreeMap::from([(1, timings(1000, 2000)), (2, timings(1000, 1000))]),
+        };
+        let current = BTreeMap::from([
+            (1, timings(1060, 3000)),
+            (2, timings(900, 1000)),
+            (3, timings(1000, 1000)),
+        ]);
+
+        let (table, regressions) = table(&current, Some(&baseline), 10.0);
+        assert_eq!(regressions, 1);
+        assert!(table.contains("3.0µs (+50%)!"));
+        assert!(table.contains("1.1µs (+6%) "));
+        assert!(table.contains("900.0ns (-10%) "));
+    }
+
+    #[test]
+    fn baseline_round_trip() {
+        let dir = temp_dir("bench-baseline");
+        fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("baseline.json");
+        let baseline = Baseline {
+            year: 2023,
+            iterations: 1,
+            days: BTreeMap::from([(
+                4,
+                Timings {
+                    parse: 12,
+                    part1: Some(34),
+                    part2: None,
+                },
+            )]),
+        };
+
+        baseline.save(&path).unwrap();
+        assert_eq!(Baseline::load(&path).unwrap(), baseline);
+        assert_eq!(Baseline::load_for(&path, 2023).unwrap(), baseline);
+        let err = Baseline::load_for(&path, 2022).unwrap_err();
+        assert!(err.to_string().ends_with("is a baseline of 2023, not 2022"));
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
@@ -0,0 +1,59 @@
+use std::{
+    env, fs,
+    path::{Path, PathBuf},
+};
+
+use anyhow::Context;
+
+/// Downloaded inputs, kept as `<dir>/<year>/dayN/input` so that a year can be read
+/// as an [`aoc_utils::InputSource::Dir`].
+pub struct Cache {
+    dir: PathBuf,
+}
+
+impl Cache {
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        Self { dir: dir.into() }
+    }
+
+    /// `$XDG_CACHE_HOME/aoc`, or `~/.cache/aoc`.
+    pub fn default_dir() -> Option<PathBuf> {
+        env::var_os("XDG_CACHE_HOME")
+            .map(PathBuf::from)
+            .or_else(|| env::var_os("HOME").map(|home| Path::new(&home).join(".cache")))
+            .map(|dir| dir.join("aoc"))
+    }
+
+    pub fn year_dir(&self, year: u16) -> PathBuf {
+        self.dir.join(year.to_string())
+    }
+
+    fn day_dir(&self, year: u16, day: u8) -> PathBuf {
+        self.year_dir(year).join(format!("day{day}"))
+    }
+
+    fn path(&self, year: u16, day: u8) -> PathBuf {
+        self.day_dir(year, day).join("input")
+    }
+
+    /// Where the answers submitted for `day` are recorded.
+    pub fn ledger(&self, year: u16, day: u8) -> PathBuf {
+        self.day_dir(year, day).join("ledger")
+    }
+
+    pub fn get(&self, year: u16, day: u8) -> Option<PathBuf> {
+        Some(self.path(year, day)).filter(|path| path.is_file())
+    }
+
+    pub fn store(&self, year: u16, day: u8, input: &str) -> anyhow::Result<PathBuf> {
+        let path = self.path(year, day);
+        let dir = path.parent().unwrap();
+        fs::create_dir_all(dir).with_context(|| format!("can't create {}", dir.display()))?;
+
+        // Written aside first so that an interrupted download is never taken for an input.
+        let partial = path.with_extension("partial");
+        fs::write(&partial, input).with_context(|| format!("can't write {}", partial.display()))?;
+        fs::rename(&partial, &path).with_context(|| format!("can't write {}", path.display()))?;
+        Ok(path)
+    }
+}
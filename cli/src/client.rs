@@ -0,0 +1,199 @@
+use anyhow::{bail, Context};
+use aoc_utils::Part;
+
+use crate::ledger::Verdict;
+
+/// What came back from the server, whatever the status.
+#[derive(Debug)]
+pub struct Response {
+    pub status: u16,
+    pub body: String,
+}
+
+/// The bits of HTTP the [`Client`] needs, so it can run on top of anything.
+pub trait Http {
+    fn get(&self, url: &str, session: &str) -> anyhow::Result<Response>;
+    fn post_form(
+        &self,
+        url: &str,
+        session: &str,
+        form: &[(&str, &str)],
+    ) -> anyhow::Result<Response>;
+}
+
+pub struct Ureq(ureq::Agent);
+
+impl Default for Ureq {
+    fn default() -> Self {
+        Self(
+            ureq::AgentBuilder::new()
+                .user_agent("github.com/dszczyt/aoc")
+                .build(),
+        )
+    }
+}
+
+impl Http for Ureq {
+    fn get(&self, url: &str, session: &str) -> anyhow::Result<Response> {
+        let response = self
+            .0
+            .get(url)
+            .set("Cookie", &format!("session={session}"))
+            .call();
+        into_response(url, response)
+    }
+
+    fn post_form(
+        &self,
+        url: &str,
+        session: &str,
+        form: &[(&str, &str)],
+    ) -> anyhow::Result<Response> {
+        let response = self
+            .0
+            .post(url)
+            .set("Cookie", &format!("session={session}"))
+            .send_form(form);
+        into_response(url, response)
+    }
+}
+
+fn into_response(
+    url: &str,
+    response: Result<ureq::Response, ureq::Error>,
+) -> anyhow::Result<Response> {
+    let response = match response {
+        Ok(response) => response,
+        Err(ureq::Error::Status(_, response)) => response,
+        Err(err) => return Err(err).with_context(|| format!("can't reach {url}")),
+    };
+    Ok(Response {
+        status: response.status(),
+        body: response.into_string()?,
+    })
+}
+
+/// Talks to the Advent of Code website, or whatever `endpoint` stands in for it.
+pub struct Client<H = Ureq> {
+    http: H,
+    endpoint: String,
+    session: Option<String>,
+}
+
+impl<H: Http> Client<H> {
+    pub fn new(http: H, endpoint: &str, session: Option<String>) -> Self {
+        Self {
+            http,
+            endpoint: endpoint.trim_end_matches('/').to_string(),
+            session,
+        }
+    }
+
+    fn session(&self) -> anyhow::Result<&str> {
+        self.session
+            .as_deref()
+            .context("no session token, set AOC_SESSION to the `session` cookie of the website")
+    }
+
+    pub fn input(&self, year: u16, day: u8) -> anyhow::Result<String> {
+        let url = format!("{}/{year}/day/{day}/input", self.endpoint);
+        let response = self.http.get(&url, self.session()?)?;
+        if response.status != 200 {
+            bail!(
+                "{url} answered {}: {}",
+                response.status,
+                response.body.trim()
+            );
+        }
+        Ok(response.body)
+    }
+
+    pub fn submit(&self, year: u16, day: u8, part: Part, answer: &str) -> anyhow::Result<Verdict> {
+        let url = format!("{}/{year}/day/{day}/answer", self.endpoint);
+        let level = match part {
+            Part::One => "1",
+            Part::Two => "2",
+        };
+        let response = self.http.post_form(
+            &url,
+            self.session()?,
+            &[("level", level), ("answer", answer)],
+        )?;
+        if response.status != 200 {
+            bail!("{url} answered {}", response.status);
+        }
+        verdict(&response.body)
+    }
+}
+
+/// Reads the verdict out of the page the website answers a submission with.
+fn verdict(page: &str) -> anyhow::Result<Verdict> {
+    let message = match (page.find("<article>"), page.find("</article>")) {
+        (Some(start), Some(end)) if start < end => &page[start..end],
+        _ => page,
+    };
+
+    if message.contains("That's the right answer") {
+        Ok(Verdict::Right)
+    } else if message.contains("too high") {
+        Ok(Verdict::TooHigh)
+    } else if message.contains("too low") {
+        Ok(Verdict::TooLow)
+    } else if message.contains("not the right answer") {
+        Ok(Verdict::Wrong)
+    } else if message.contains("You gave an answer too recently") {
+        Ok(Verdict::RateLimited)
+    } else {
+        let text: String = strip_tags(message)
+            .split_whitespace()
+            .collect::<Vec<_>>()
+            .join(" ");
+        bail!("unexpected answer from the website: {text}")
+    }
+}
+
+fn strip_tags(html: &str) -> String {
+    let mut in_tag = false;
+    html.chars()
+        .filter(|c| match c {
+            '<' => {
+                in_tag = true;
+                false
+            }
+            '>' => {
+                in_tag = false;
+                false
+            }
+            _ => !in_tag,
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::verdict;
+    use crate::ledger::Verdict;
+
+    #[test]
+    fn verdicts() {
+        let page = |message: &str| format!("<main>\n<article><p>{message}</p></article>\n</main>");
+
+        let right =
+            "That's the right answer! You are one gold star closer to saving your vacation.";
+        assert_eq!(verdict(&page(right)).unwrap(), Verdict::Right);
+        let high = "That's not the right answer; your answer is too high. If you're stuck, [...]";
+        assert_eq!(verdict(&page(high)).unwrap(), Verdict::TooHigh);
+        let low = "That's not the right answer; your answer is too low. Please wait one minute.";
+        assert_eq!(verdict(&page(low)).unwrap(), Verdict::TooLow);
+        let wrong = "That's not the right answer. If you're stuck, make sure you're using the full input data.";
+        assert_eq!(verdict(&page(wrong)).unwrap(), Verdict::Wrong);
+        let limited = "You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 38s left to wait.";
+        assert_eq!(verdict(&page(limited)).unwrap(), Verdict::RateLimited);
+
+        let level = "You don't seem to be solving the right level.  Did you already complete it? <a href=\"/2022/day/1\">[Return to Day 1]</a>";
+        assert_eq!(
+            verdict(&page(level)).unwrap_err().to_string(),
+            "unexpected answer from the website: You don't seem to be solving the right level. Did you already complete it? [Return to Day 1]"
+        );
+    }
+}
@@ -0,0 +1,75 @@
+use std::path::PathBuf;
+
+use crate::{
+    cache::Cache,
+    client::{Client, Http},
+};
+
+/// Where the input of `day` is cached, downloading it first if it isn't yet.
+pub fn fetch<H: Http>(
+    client: &Client<H>,
+    cache: &Cache,
+    year: u16,
+    day: u8,
+) -> anyhow::Result<PathBuf> {
+    if let Some(path) = cache.get(year, day) {
+        return Ok(path);
+    }
+    let input = client.input(year, day)?;
+    cache.store(year, day, &input)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use super::fetch;
+    use crate::{
+        cache::Cache,
+        client::{Client, Ureq},
+        test_server::{temp_dir, TestServer},
+    };
+
+    #[test]
+    fn fetch_once() {
+        let server = TestServer::start(|request| {
+            assert_eq!(request.url, "/2022/day/1/input");
+            assert_eq!(request.cookie.as_deref(), Some("session=secret"));
+            (200, "1000\n2000\n".to_string())
+        });
+        let client = Client::new(Ureq::default(), &server.url(), Some("secret".into()));
+        let dir = temp_dir("fetch-once");
+        let cache = Cache::new(&dir);
+
+        let path = fetch(&client, &cache, 2022, 1).unwrap();
+        assert_eq!(path, dir.join("2022/day1/input"));
+        assert_eq!(fs::read_to_string(&path).unwrap(), "1000\n2000\n");
+
+        assert_eq!(fetch(&client, &cache, 2022, 1).unwrap(), path);
+        assert_eq!(server.requests(), 1);
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn nothing_cached_on_error() {
+        let server = TestServer::start(|_| (404, "Not Found".to_string()));
+        let client = Client::new(Ureq::default(), &server.url(), Some("secret".into()));
+        let dir = temp_dir("fetch-error");
+        let cache = Cache::new(&dir);
+
+        let err = fetch(&client, &cache, 2022, 30).unwrap_err().to_string();
+        assert!(err.ends_with("/2022/day/30/input answered 404: Not Found"));
+        assert!(cache.get(2022, 30).is_none());
+    }
+
+    #[test]
+    fn session_required() {
+        let client = Client::new(Ureq::default(), "http://127.0.0.1:1", None);
+        let dir = temp_dir("fetch-session");
+        let cache = Cache::new(&dir);
+
+        let err = fetch(&client, &cache, 2022, 1).unwrap_err().to_string();
+        assert!(err.starts_with("no session token"));
+    }
+}
@@ -0,0 +1,231 @@
+use std::{
+    fmt::{self, Display},
+    fs,
+    io::Write,
+    path::PathBuf,
+    str::FromStr,
+};
+
+use anyhow::{bail, Context};
+use aoc_utils::Part;
+
+/// What the website said about a submitted answer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Verdict {
+    Right,
+    Wrong,
+    TooHigh,
+    TooLow,
+    RateLimited,
+}
+
+impl Verdict {
+    /// Whether the verdict tells something about the answer itself.
+    fn is_conclusive(self) -> bool {
+        self != Verdict::RateLimited
+    }
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Verdict::Right => "right",
+            Verdict::Wrong => "wrong",
+            Verdict::TooHigh => "too high",
+            Verdict::TooLow => "too low",
+            Verdict::RateLimited => "rate limited",
+        })
+    }
+}
+
+impl FromStr for Verdict {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "right" => Verdict::Right,
+            "wrong" => Verdict::Wrong,
+            "too high" => Verdict::TooHigh,
+            "too low" => Verdict::TooLow,
+            "rate limited" => Verdict::RateLimited,
+            _ => bail!("unknown verdict: {s}"),
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Entry {
+    pub part: Part,
+    pub answer: String,
+    pub verdict: Verdict,
+}
+
+/// Every answer submitted for a day, one `part<TAB>verdict<TAB>answer` line each.
+pub struct Ledger {
+    path: PathBuf,
+    entries: Vec<Entry>,
+}
+
+impl Ledger {
+    pub fn open(path: impl Into<PathBuf>) -> anyhow::Result<Self> {
+        let path = path.into();
+        let entries = match fs::read_to_string(&path) {
+            Ok(content) => content
+                .lines()
+                .enumerate()
+                .map(|(i, line)| {
+                    parse_entry(line)
+                        .with_context(|| format!("{}:{}: bad entry", path.display(), i + 1))
+                })
+                .collect::<anyhow::Result<_>>()?,
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => vec![],
+            Err(err) => return Err(err).with_context(|| format!("can't read {}", path.display())),
+        };
+        Ok(Self { path, entries })
+    }
+
+    /// What is already known about `answer`, without having to submit it.
+    pub fn verdict(&self, part: Part, answer: &str) -> Option<Verdict> {
+        let entries = || {
+            self.entries
+                .iter()
+                .filter(move |entry| entry.part == part && entry.verdict.is_conclusive())
+        };
+
+        if let Some(entry) = entries().find(|entry| entry.answer == answer) {
+            return Some(entry.verdict);
+        }
+        if entries().any(|entry| entry.verdict == Verdict::Right) {
+            return Some(Verdict::Wrong);
+        }
+
+        let answer: i64 = answer.parse().ok()?;
+        let bound = |verdict| {
+            entries()
+                .filter(move |entry| entry.verdict == verdict)
+                .filter_map(|entry| entry.answer.parse::<i64>().ok())
+        };
+        if bound(Verdict::TooHigh).any(|high| answer >= high) {
+            return Some(Verdict::TooHigh);
+        }
+        if bound(Verdict::TooLow).any(|low| answer <= low) {
+            return Some(Verdict::TooLow);
+        }
+        None
+    }
+
+    /// Fails for answers the ledger can't keep, its fields being split by tabs
+    /// and its entries by newlines.
+    pub fn check(answer: &str) -> anyhow::Result<()> {
+        if answer.contains(['\t', '\n', '\r']) {
+            bail!("answers can't contain tabs or line breaks: {answer:?}");
+        }
+        Ok(())
+    }
+
+    pub fn record(&mut self, entry: Entry) -> anyhow::Result<()> {
+        Self::check(&entry.answer)?;
+        if let Some(dir) = self.path.parent() {
+            fs::create_dir_all(dir).with_context(|| format!("can't create {}", dir.display()))?;
+        }
+        let mut file = fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)
+            .with_context(|| format!("can't write {}", self.path.display()))?;
+        writeln!(file, "{}\t{}\t{}", entry.part, entry.verdict, entry.answer)?;
+        self.entries.push(entry);
+        Ok(())
+    }
+}
+
+fn parse_entry(line: &str) -> anyhow::Result<Entry> {
+    let mut fields = line.splitn(3, '\t');
+    let mut field = || fields.next().context("missing field");
+    Ok(Entry {
+        part: field()?.parse()?,
+        verdict: field()?.parse()?,
+        answer: field()?.to_string(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use aoc_utils::Part;
+
+    use super::{Entry, Ledger, Verdict};
+    use crate::test_server::temp_dir;
+
+    fn entry(part: Part, answer: &str, verdict: Verdict) -> Entry {
+        Entry {
+            part,
+            answer: answer.to_string(),
+            verdict,
+        }
+    }
+
+    #[test]
+    fn learned_bounds() {
+        let dir = temp_dir("ledger-bounds");
+        let mut ledger = Ledger::open(dir.join("ledger")).unwrap();
+        ledger
+            .record(entry(Part::One, "100", Verdict::TooHigh))
+            .unwrap();
+        ledger
+            .record(entry(Part::One, "10", Verdict::TooLow))
+            .unwrap();
+        ledger
+            .record(entry(Part::One, "50", Verdict::Wrong))
+            .unwrap();
+        ledger
+            .record(entry(Part::One, "42", Verdict::RateLimited))
+            .unwrap();
+
+        assert_eq!(ledger.verdict(Part::One, "50"), Some(Verdict::Wrong));
+        assert_eq!(ledger.verdict(Part::One, "100"), Some(Verdict::TooHigh));
+        assert_eq!(ledger.verdict(Part::One, "150"), Some(Verdict::TooHigh));
+        assert_eq!(ledger.verdict(Part::One, "3"), Some(Verdict::TooLow));
+        assert_eq!(ledger.verdict(Part::One, "42"), None);
+        assert_eq!(ledger.verdict(Part::One, "abc"), None);
+        assert_eq!(ledger.verdict(Part::Two, "150"), None);
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn only_one_right_answer() {
+        let dir = temp_dir("ledger-right");
+        let mut ledger = Ledger::open(dir.join("ledger")).unwrap();
+        ledger
+            .record(entry(Part::Two, "ABC", Verdict::Right))
+            .unwrap();
+
+        let ledger = Ledger::open(dir.join("ledger")).unwrap();
+        assert_eq!(ledger.verdict(Part::Two, "ABC"), Some(Verdict::Right));
+        assert_eq!(ledger.verdict(Part::Two, "ABD"), Some(Verdict::Wrong));
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn answers_stay_on_their_line() {
+        let dir = temp_dir("ledger-lines");
+        let mut ledger = Ledger::open(dir.join("ledger")).unwrap();
+        for answer in ["1\t2", "AB\nCD", "AB\r\n"] {
+            assert!(ledger
+                .record(entry(Part::One, answer, Verdict::Wrong))
+                .is_err());
+        }
+        ledger
+            .record(entry(Part::One, "12", Verdict::TooLow))
+            .unwrap();
+
+        let ledger = Ledger::open(dir.join("ledger")).unwrap();
+        assert_eq!(ledger.verdict(Part::One, "12"), Some(Verdict::TooLow));
+        assert_eq!(ledger.verdict(Part::One, "1\t2"), None);
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
@@ -0,0 +1,274 @@
+use std::{
+    collections::BTreeMap,
+    path::{Path, PathBuf},
+};
+
+use anyhow::{bail, Context};
+use aoc_utils::{InputSource, Part, Registry};
+use bench::Baseline;
+use cache::Cache;
+use clap::{Args, Parser, Subcommand};
+use client::{Client, Ureq};
+use ledger::Ledger;
+
+mod bench;
+mod cache;
+mod client;
+mod fetch;
+mod ledger;
+mod new;
+mod run;
+mod submit;
+#[cfg(test)]
+mod test_server;
+
+#[derive(Parser)]
+#[command(about = "Advent of Code solutions")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+    /// Where downloaded inputs are kept [default: ~/.cache/aoc]
+    #[arg(long, global = true, env = "AOC_CACHE", value_name = "DIR")]
+    cache: Option<PathBuf>,
+}
+
+/// Where to read the input of a day from.
+#[derive(Args)]
+struct Inputs {
+    /// Read the puzzle input from this file, or stdin for `-`
+    #[arg(short, long)]
+    input: Option<PathBuf>,
+    /// Look for inputs in DIR/<year>/dayN instead of the cached or shipped ones
+    #[arg(long, env = "AOC_INPUTS", value_name = "DIR")]
+    inputs: Option<PathBuf>,
+}
+
+/// How to reach the website.
+#[derive(Args)]
+struct Remote {
+    /// The website, or anything standing in for it
+    #[arg(long, env = "AOC_ENDPOINT", default_value = "https://adventofcode.com")]
+    endpoint: String,
+    /// The `session` cookie of a logged in browser
+    #[arg(long, env = "AOC_SESSION", hide_env_values = true)]
+    session: Option<String>,
+}
+
+impl Remote {
+    fn client(self) -> Client {
+        Client::new(Ureq::default(), &self.endpoint, self.session)
+    }
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Time the parsing and both parts of every day of a year
+    Bench {
+        year: u16,
+        /// Only this day
+        #[arg(long)]
+        day: Option<u8>,
+        /// How many times each day is run, the mean time being reported
+        #[arg(short = 'n', long, default_value_t = 10, value_parser = clap::value_parser!(u32).range(1..))]
+        iterations: u32,
+        /// Look for inputs in DIR/<year>/dayN instead of the cached or shipped ones
+        #[arg(long, env = "AOC_INPUTS", value_name = "DIR")]
+        inputs: Option<PathBuf>,
+        /// Save the timings as a baseline
+        #[arg(long, value_name = "FILE")]
+        save: Option<PathBuf>,
+        /// Compare the timings to a saved baseline, failing on regressions
+        #[arg(long, value_name = "FILE")]
+        compare: Option<PathBuf>,
+        /// How many percent slower than the baseline is a regression
+        #[arg(long, default_value_t = 10.0)]
+        threshold: f64,
+    },
+    /// Start a day: its module, empty sample and input files, and tests to fill in
+    New {
+        year: u16,
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+    },
+    /// Solve a day and print its answers
+    Run {
+        year: u16,
+        day: u8,
+        /// Only solve this part (1 or 2)
+        #[arg(short, long)]
+        part: Option<Part>,
+        #[command(flatten)]
+        inputs: Inputs,
+        /// Also print what each part went through, on stderr
+        #[arg(short, long)]
+        verbose: bool,
+    },
+    /// Download the input of a day, unless it is already cached
+    Fetch {
+        year: u16,
+        day: u8,
+        #[command(flatten)]
+        remote: Remote,
+    },
+    /// Solve a part and submit its answer, unless the answer is already known to be wrong
+    Submit {
+        year: u16,
+        day: u8,
+        part: Part,
+        /// Submit this instead of solving the part
+        #[arg(long)]
+        answer: Option<String>,
+        #[command(flatten)]
+        inputs: Inputs,
+        #[command(flatten)]
+        remote: Remote,
+    },
+}
+
+fn registry() -> Registry {
+    let mut registry = Registry::new();
+    aoc2022::register(&mut registry);
+    aoc2023::register(&mut registry);
+    registry
+}
+
+/// Where the inputs shipped with the solutions of `year` live.
+fn shipped_inputs(year: u16) -> Option<&'static Path> {
+    match year {
+        aoc2022::YEAR => Some(Path::new(aoc2022::INPUTS)),
+        aoc2023::YEAR => Some(Path::new(aoc2023::INPUTS)),
+        _ => None,
+    }
+}
+
+fn cache(dir: Option<PathBuf>) -> anyhow::Result<Cache> {
+    dir.or_else(Cache::default_dir)
+        .map(Cache::new)
+        .context("can't tell where to cache inputs, set AOC_CACHE")
+}
+
+fn input_source(
+    cache: Option<&Cache>,
+    year: u16,
+    day: u8,
+    Inputs { input, inputs }: Inputs,
+) -> anyhow::Result<InputSource> {
+    if let Some(input) = input {
+        return InputSource::from_arg(&input);
+    }
+    if let Some(inputs) = inputs {
+        return Ok(InputSource::Dir(inputs.join(year.to_string())));
+    }
+    if let Some(cache) = cache.filter(|cache| cache.get(year, day).is_some()) {
+        return Ok(InputSource::Dir(cache.year_dir(year)));
+    }
+    shipped_inputs(year)
+        .map(|dir| InputSource::Dir(dir.to_path_buf()))
+        .with_context(|| format!("no input for {year} day {day}, fetch it or use --input"))
+}
+
+fn main() -> anyhow::Result<()> {
+    let registry = registry();
+
+    let cli = Cli::parse();
+
+    match cli.command {
+        Command::Bench {
+            year,
+            day,
+            iterations,
+            inputs,
+            save,
+            compare,
+            threshold,
+        } => {
+            let cache = cache(cli.cache).ok();
+            let baseline = compare
+                .as_deref()
+                .map(|path| Baseline::load_for(path, year))
+                .transpose()?;
+
+            let mut days = BTreeMap::new();
+            for solution in registry
+                .year(year)
+                .filter(|d| day.is_none_or(|day| d.day == day))
+            {
+                let inputs = Inputs {
+                    input: None,
+                    inputs: inputs.clone(),
+                };
+                let timings = input_source(cache.as_ref(), year, solution.day, inputs)
+                    .and_then(|source| bench::bench(solution, &source, iterations));
+                match timings {
+                    Ok(timings) => {
+                        days.insert(solution.day, timings);
+                    }
+                    Err(err) => eprintln!("day {}: {err:#}", solution.day),
+                }
+            }
+
+            let (table, regressions) = bench::table(&days, baseline.as_ref(), threshold);
+            print!("{table}");
+
+            if let Some(path) = save {
+                Baseline {
+                    year,
+                    iterations,
+                    days,
+                }
+                .save(&path)?;
+            }
+            if regressions > 0 {
+                bail!("{regressions} timings are more than {threshold}% slower than the baseline");
+            }
+            Ok(())
+        }
+        Command::New { year, day } => {
+            let src = shipped_inputs(year)
+                .with_context(|| format!("no crate for {year}, add one to the workspace first"))?;
+            for path in new::new(src, new::Layout::of(year), day)? {
+                println!("{}", path.display());
+            }
+            Ok(())
+        }
+        Command::Run {
+            year,
+            day,
+            part,
+            inputs,
+            verbose,
+        } => {
+            let cache = cache(cli.cache).ok();
+            let source = input_source(cache.as_ref(), year, day, inputs)?;
+            run::run(&registry, year, day, part, &source, verbose)
+        }
+        Command::Fetch { year, day, remote } => {
+            let path = fetch::fetch(&remote.client(), &cache(cli.cache)?, year, day)?;
+            println!("{}", path.display());
+            Ok(())
+        }
+        Command::Submit {
+            year,
+            day,
+            part,
+            answer,
+            inputs,
+            remote,
+        } => {
+            let cache = cache(cli.cache)?;
+            let answer = match answer {
+                Some(answer) => answer,
+                None => {
+                    let source = input_source(Some(&cache), year, day, inputs)?;
+                    run::solution(&registry, year, day)?
+                        .solve(&source.read(day, part)?, part)?
+                        .to_string()
+                }
+            };
+            let mut ledger = Ledger::open(cache.ledger(year, day))?;
+            let verdict = submit::submit(&remote.client(), &mut ledger, year, day, part, &answer)?;
+            println!("{answer}: {verdict}");
+            Ok(())
+        }
+    }
+}
@@ -0,0 +1,223 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use anyhow::{bail, Context};
+
+/// How a year lays out the data files of its days.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Layout {
+    /// `example` and `input`, shared by both parts.
+    Flat,
+    /// `samples/partN` and `inputs/partN`, part 2 falling back to part 1.
+    PerPart,
+}
+
+impl Layout {
+    pub fn of(year: u16) -> Self {
+        if year <= 2022 {
+            Layout::Flat
+        } else {
+            Layout::PerPart
+        }
+    }
+
+    fn sample(self) -> &'static str {
+        match self {
+            Layout::Flat => "example",
+            Layout::PerPart => "samples/part1",
+        }
+    }
+
+    fn input(self) -> &'static str {
+        match self {
+            Layout::Flat => "input",
+            Layout::PerPart => "inputs/part1",
+        }
+    }
+}
+
+/// Starts `day` in `src`, the sources of a year: its module with a test module
+/// reading the sample, empty data files, and its registration in `lib.rs`.
+/// Returns the files created.
+pub fn new(src: &Path, layout: Layout, day: u8) -> anyhow::Result<Vec<PathBuf>> {
+    let dir = src.join(format!("day{day}"));
+    if dir.exists() {
+        bail!("{} already exists", dir.display());
+    }
+    let lib = src.join("lib.rs");
+    let lib_rs =
+        fs::read_to_string(&lib).with_context(|| format!("can't read {}", lib.display()))?;
+    let lib_rs = register(&lib_rs, day).with_context(|| format!("in {}", lib.display()))?;
+
+    let files = [
+        (dir.join("mod.rs"), module(layout, day)),
+        (dir.join(layout.sample()), String::new()),
+        (dir.join(layout.input()), String::new()),
+    ];
+    for (path, contents) in &files {
+        let parent = path.parent().unwrap();
+        fs::create_dir_all(parent).with_context(|| format!("can't create {}", parent.display()))?;
+        fs::write(path, contents).with_context(|| format!("can't write {}", path.display()))?;
+    }
+    fs::write(&lib, lib_rs).with_context(|| format!("can't write {}", lib.display()))?;
+
+    Ok(files.into_iter().map(|(path, _)| path).collect())
+}
+
+/// `lib_rs` with `pub mod dayN;` among the other days, in the order rustfmt keeps
+/// them, and `dayN` registered after the last one.
+fn register(lib_rs: &str, day: u8) -> anyhow::Result<String> {
+    let module = format!("pub mod day{day};");
+    let mut lines: Vec<String> = lib_rs.lines().map(str::to_string).collect();
+
+    let modules: Vec<usize> = (0..lines.len())
+        .filter(|&i| lines[i].starts_with("pub mod day"))
+        .collect();
+    let Some(&last_module) = modules.last() else {
+        bail!("no `pub mod dayN;` to add day{day} next to");
+    };
+    if modules.iter().any(|&i| lines[i] == module) {
+        bail!("day{day} is already declared");
+    }
+    let at = modules
+        .iter()
+        .copied()
+        .find(|&i| lines[i].trim_end_matches(';') > module.trim_end_matches(';'))
+        .unwrap_or(last_module + 1);
+    lines.insert(at, module);
+
+    let Some(last) = lines
+        .iter()
+        .rposition(|line| line.trim_start().starts_with(".register::<"))
+    else {
+        bail!("no `.register::<..>(YEAR, N)` to register day{day} after");
+    };
+    let Some(registration) = lines[last].strip_suffix(';') else {
+        bail!("the last registration doesn't end the statement");
+    };
+    let indent = &registration[..registration.len() - registration.trim_start().len()];
+    let added = format!("{indent}.register::<day{day}::Day{day}>(YEAR, {day});");
+    lines[last] = registration.to_string();
+    lines.insert(last + 1, added);
+
+    Ok(lines.join("\n") + "\n")
+}
+
+/// The module of a day that solves nothing yet, with tests expecting placeholders.
+fn module(layout: Layout, day: u8) -> String {
+    let name = format!("Day{day}");
+    let sample = layout.sample();
+    format!(
+        r#"use aoc_utils::{{Answer, Solution, Unsolved}};
+
+pub struct {name};
+
+impl Solution for {name} {{
+    type Input = String;
+
+    fn parse(input: &str) -> anyhow::Result<Self::Input> {{
+        Ok(input.to_string())
+    }}
+
+    fn part1(_input: &Self::Input) -> anyhow::Result<Answer> {{
+        Err(Unsolved.into())
+    }}
+
+    fn part2(_input: &Self::Input) -> anyhow::Result<Answer> {{
+        Err(Unsolved.into())
+    }}
+}}
+
+#[cfg(test)]
+mod test {{
+    use aoc_utils::Solution;
+
+    use super::{name};
+
+    #[test]
+    fn part1() {{
+        let input = {name}::parse(include_str!("{sample}")).unwrap();
+        assert_eq!({name}::part1(&input).unwrap().to_string(), "TODO");
+    }}
+
+    #[test]
+    fn part2() {{
+        let input = {name}::parse(include_str!("{sample}")).unwrap();
+        assert_eq!({name}::part2(&input).unwrap().to_string(), "TODO");
+    }}
+}}
+"#
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use super::{new, register, Layout};
+    use crate::test_server::temp_dir;
+
+    const LIB_RS: &str = "use aoc_utils::Registry;
+
+pub mod day1;
+pub mod day2;
+pub mod day9;
+
+pub const YEAR: u16 = 2022;
+
+pub fn register(registry: &mut Registry) {
+    registry
+        .register::<day1::Day1>(YEAR, 1)
+        .register::<day2::Day2>(YEAR, 2)
+        .register::<day9::Day9>(YEAR, 9);
+}
+";
+
+    #[test]
+    fn registered_in_order() {
+        let lib_rs = register(LIB_RS, 10).unwrap();
+        assert_eq!(
+            lib_rs,
+            LIB_RS
+                .replace("pub mod day2;", "pub mod day10;\npub mod day2;")
+                .replace(
+                    "(YEAR, 9);",
+                    "(YEAR, 9)\n        .register::<day10::Day10>(YEAR, 10);"
+                )
+        );
+
+        let err = register(LIB_RS, 2).unwrap_err();
+        assert_eq!(err.to_string(), "day2 is already declared");
+    }
+
+    #[test]
+    fn layouts() {
+        let src = temp_dir("new-layouts");
+        fs::create_dir_all(&src).unwrap();
+        fs::write(src.join("lib.rs"), LIB_RS).unwrap();
+
+        let files = new(&src, Layout::Flat, 10).unwrap();
+        assert_eq!(
+            files,
+            ["mod.rs", "example", "input"].map(|file| src.join("day10").join(file))
+        );
+        let module = fs::read_to_string(&files[0]).unwrap();
+        assert!(module.contains("pub struct Day10;"));
+        assert!(module.contains(r#"Day10::parse(include_str!("example"))"#));
+        assert!(fs::read_to_string(src.join("lib.rs"))
+            .unwrap()
+            .contains("pub mod day10;"));
+
+        let files = new(&src, Layout::PerPart, 11).unwrap();
+        assert_eq!(files[1], src.join("day11/samples/part1"));
+        assert_eq!(files[2], src.join("day11/inputs/part1"));
+        assert_eq!(fs::read_to_string(&files[2]).unwrap(), "");
+
+        let err = new(&src, Layout::Flat, 10).unwrap_err();
+        assert!(err.to_string().ends_with("day10 already exists"));
+
+        fs::remove_dir_all(src).unwrap();
+    }
+}
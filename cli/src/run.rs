@@ -0,0 +1,51 @@
+use anyhow::Context;
+use aoc_utils::{Day, InputSource, Part, Puzzle, Registry, Unsolved};
+
+pub fn solution(registry: &Registry, year: u16, day: u8) -> anyhow::Result<&Day> {
+    registry
+        .get(year, day)
+        .with_context(|| format!("no solution for {year} day {day}"))
+}
+
+pub fn run(
+    registry: &Registry,
+    year: u16,
+    day: u8,
+    part: Option<Part>,
+    source: &InputSource,
+    verbose: bool,
+) -> anyhow::Result<()> {
+    let solution = solution(registry, year, day)?;
+
+    match part {
+        Some(part) => {
+            let puzzle = solution.parse(&source.read(day, part)?)?;
+            if verbose {
+                render(&*puzzle, part)?;
+            }
+            println!("{}", puzzle.solve(part)?);
+        }
+        None => {
+            for part in [Part::One, Part::Two] {
+                let puzzle = solution.parse(&source.read(day, part)?)?;
+                if verbose {
+                    render(&*puzzle, part)?;
+                }
+                match puzzle.solve(part) {
+                    Ok(answer) => println!("{part}: {answer}"),
+                    Err(err) if err.is::<Unsolved>() => println!("{part}: {err}"),
+                    Err(err) => return Err(err.context(format!("{part} failed"))),
+                }
+            }
+        }
+    }
+
+    Ok(())
+}
+
+fn render(puzzle: &dyn Puzzle, part: Part) -> anyhow::Result<()> {
+    if let Some(rendering) = puzzle.render(part)? {
+        eprintln!("{rendering}");
+    }
+    Ok(())
+}
@@ -0,0 +1,74 @@
+use aoc_utils::Part;
+
+use crate::{
+    client::{Client, Http},
+    ledger::{Entry, Ledger, Verdict},
+};
+
+/// Submits `answer` unless the ledger already knows what the website would say.
+pub fn submit<H: Http>(
+    client: &Client<H>,
+    ledger: &mut Ledger,
+    year: u16,
+    day: u8,
+    part: Part,
+    answer: &str,
+) -> anyhow::Result<Verdict> {
+    Ledger::check(answer)?;
+    if let Some(verdict) = ledger.verdict(part, answer) {
+        if verdict == Verdict::Right {
+            return Ok(verdict);
+        }
+        anyhow::bail!("not submitting {answer}, it is known to be {verdict}");
+    }
+
+    let verdict = client.submit(year, day, part, answer)?;
+    ledger.record(Entry {
+        part,
+        answer: answer.to_string(),
+        verdict,
+    })?;
+    Ok(verdict)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use aoc_utils::Part;
+
+    use super::submit;
+    use crate::{
+        client::{Client, Ureq},
+        ledger::{Ledger, Verdict},
+        test_server::{temp_dir, TestServer},
+    };
+
+    #[test]
+    fn wrong_answers_are_not_resubmitted() {
+        let server = TestServer::start(|request| {
+            assert_eq!(request.method, "POST");
+            assert_eq!(request.url, "/2022/day/1/answer");
+            let body = if request.body == "level=1&answer=24000" {
+                "<article><p>That's the right answer!</p></article>"
+            } else {
+                "<article><p>That's not the right answer; your answer is too low.</p></article>"
+            };
+            (200, body.to_string())
+        });
+        let client = Client::new(Ureq::default(), &server.url(), Some("secret".into()));
+        let dir = temp_dir("submit");
+        let mut ledger = Ledger::open(dir.join("ledger")).unwrap();
+
+        let submit =
+            |ledger: &mut Ledger, answer| submit(&client, ledger, 2022, 1, Part::One, answer);
+        assert_eq!(submit(&mut ledger, "2000").unwrap(), Verdict::TooLow);
+        assert!(submit(&mut ledger, "1500").is_err());
+        assert_eq!(submit(&mut ledger, "24000").unwrap(), Verdict::Right);
+        assert_eq!(submit(&mut ledger, "24000").unwrap(), Verdict::Right);
+        assert!(submit(&mut ledger, "25000").is_err());
+        assert_eq!(server.requests(), 2);
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
@@ -0,0 +1,171 @@
+use std::{env, fs, path::Path};
+
+use anyhow::{bail, Context};
+
+use crate::{Part, Registry};
+
+/// One line of an `answers` file: `dayN partN <input> <answer>`.
+///
+/// The input is a file of the `dayN` directory; an answer starting with `@` is
+/// the content of such a file, for answers that span several lines.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Expected {
+    pub day: u8,
+    pub part: Part,
+    pub input: String,
+    pub answer: String,
+}
+
+impl Expected {
+    /// A name for the test checking this answer, e.g. `day12_part2_example_part1`.
+    pub fn test_name(&self) -> String {
+        let input: String = self
+            .input
+            .chars()
+            .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+            .collect();
+        format!("day{}_{}_{}", self.day, self.part, input)
+    }
+}
+
+/// Reads an `answers` file, skipping blank lines and `#` comments.
+pub fn parse_answers(text: &str) -> anyhow::Result<Vec<Expected>> {
+    text.lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty() && !line.trim_start().starts_with('#'))
+        .map(|(i, line)| parse_line(line).with_context(|| format!("line {}: {line}", i + 1)))
+        .collect()
+}
+
+fn parse_line(line: &str) -> anyhow::Result<Expected> {
+    let mut fields = line.split_whitespace();
+    let mut field = |name| fields.next().with_context(|| format!("missing {name}"));
+
+    let day = field("day")?;
+    let day = day
+        .strip_prefix("day")
+        .and_then(|day| day.parse().ok())
+        .with_context(|| format!("bad day: {day}"))?;
+    let part = field("part")?.parse()?;
+    let input = field("input")?.to_string();
+    let answer = field("answer")?.to_string();
+    if fields.next().is_some() {
+        bail!("answers can't contain spaces, put it in a file and use @file");
+    }
+
+    Ok(Expected {
+        day,
+        part,
+        input,
+        answer,
+    })
+}
+
+/// Solves `expected` with the solution registered for `year`, reading files from
+/// the `dayN` directories of `dir`, and panics if the answer differs.
+pub fn check(registry: &Registry, year: u16, dir: &Path, expected: &Expected) {
+    let day_dir = dir.join(format!("day{}", expected.day));
+    let read = |file: &str| {
+        let path = day_dir.join(file);
+        fs::read_to_string(&path).unwrap_or_else(|err| panic!("{}: {err}", path.display()))
+    };
+
+    let input = read(&expected.input);
+    let answer = match expected.answer.strip_prefix('@') {
+        Some(file) => read(file).trim_end().to_string(),
+        None => expected.answer.clone(),
+    };
+
+    let day = registry
+        .get(year, expected.day)
+        .unwrap_or_else(|| panic!("no solution for {year} day {}", expected.day));
+    let solved = day
+        .solve(&input, expected.part)
+        .unwrap_or_else(|err| panic!("{err:#}"));
+    assert_eq!(solved.to_string(), answer);
+}
+
+/// The tests checking `answers`, each calling a `check(&Expected)` in scope.
+pub fn tests(answers: &[Expected]) -> String {
+    answers
+        .iter()
+        .map(|expected| {
+            format!(
+                "
+#[test]
+fn {}() {{
+    check(&Expected {{
+        day: {},
+        part: Part::{:?},
+        input: {:?}.into(),
+        answer: {:?}.into(),
+    }});
+}}
+",
+                expected.test_name(),
+                expected.day,
+                expected.part,
+                expected.input,
+                expected.answer,
+            )
+        })
+        .collect()
+}
+
+/// Turns every line of the crate's `answers` file into a test, written to
+/// `answers.rs` in `OUT_DIR` for `tests/answers.rs` to include; meant to be
+/// the whole of a build script.
+pub fn generate() {
+    println!("cargo:rerun-if-changed=answers");
+
+    let answers = fs::read_to_string("answers").expect("can't read answers");
+    let answers = parse_answers(&answers).unwrap_or_else(|err| panic!("answers: {err:#}"));
+
+    let out = Path::new(&env::var("OUT_DIR").unwrap()).join("answers.rs");
+    fs::write(out, tests(&answers)).unwrap();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse_answers, tests, Expected};
+    use crate::Part;
+
+    #[test]
+    fn parse() {
+        let answers = parse_answers(
+            "# comment\n\nday1 part1 input 24000\nday10 part2 example1_2 @expected_part2\n",
+        )
+        .unwrap();
+        assert_eq!(
+            answers,
+            vec![
+                Expected {
+                    day: 1,
+                    part: Part::One,
+                    input: "input".into(),
+                    answer: "24000".into(),
+                },
+                Expected {
+                    day: 10,
+                    part: Part::Two,
+                    input: "example1_2".into(),
+                    answer: "@expected_part2".into(),
+                },
+            ]
+        );
+        assert_eq!(answers[1].test_name(), "day10_part2_example1_2");
+
+        assert!(parse_answers("day1 part3 input 1").is_err());
+        assert!(parse_answers("1 part1 input 1").is_err());
+        assert!(parse_answers("day1 part1 input").is_err());
+    }
+
+    #[test]
+    fn generated() {
+        let answers = parse_answers("day1 part1 input 24000\n").unwrap();
+        let code = tests(&answers);
+        assert!(code.contains("fn day1_part1_input() {"));
+        assert!(code.contains("part: Part::One,"));
+        assert!(code.contains("answer: \"24000\".into(),"));
+    }
+}
@@ -0,0 +1,328 @@
+use std::{
+    fmt::{self, Display},
+    ops::{Index, IndexMut},
+};
+
+use crate::{Direction, ParseError, Point2, Vec2};
+
+/// A position in a [`Grid`], from the top left corner.
+pub type Pos = Point2<usize>;
+
+/// The four orthogonal steps, clockwise from up.
+pub const ORTHOGONAL: [Vec2<isize>; 4] = [
+    Direction::Up.step(),
+    Direction::Right.step(),
+    Direction::Down.step(),
+    Direction::Left.step(),
+];
+
+/// The eight steps to the cells around another, clockwise from up.
+pub const AROUND: [Vec2<isize>; 8] = [
+    Vec2::new(0, -1),
+    Vec2::new(1, -1),
+    Vec2::new(1, 0),
+    Vec2::new(1, 1),
+    Vec2::new(0, 1),
+    Vec2::new(-1, 1),
+    Vec2::new(-1, 0),
+    Vec2::new(-1, -1),
+];
+
+/// A rectangle of cells, stored row by row.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// A grid of `width` columns, `cells` being its rows one after the other.
+    /// Without columns there's no telling how many rows there are, so it has none:
+    /// see [`Grid::filled`] for that.
+    ///
+    /// Panics if `cells` doesn't fill whole rows.
+    pub fn new(width: usize, cells: Vec<T>) -> Self {
+        assert!(
+            cells.len().is_multiple_of(width),
+            "{} cells don't make rows of {width}",
+            cells.len()
+        );
+        let height = cells.len().checked_div(width).unwrap_or(0);
+        Self {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    /// A grid of `width` by `height` cells, all `value`.
+    pub fn filled(width: usize, height: usize, value: T) -> Self
+    where
+        T: Clone,
+    {
+        Self {
+            width,
+            height,
+            cells: vec![value; width * height],
+        }
+    }
+
+    /// Parses every character of every line of `input` with `cell`, which returns
+    /// `None` for the characters that aren't `expected`.
+    pub fn from_chars(
+        input: &str,
+        expected: &str,
+        mut cell: impl FnMut(char) -> Option<T>,
+    ) -> Result<Self, ParseError> {
+        let mut width = None;
+        let mut height = 0;
+        let mut cells = vec![];
+        for line in input.lines() {
+            height += 1;
+            let mut row_width = 0;
+            for (i, c) in line.char_indices() {
+                let found = &line[i..i + c.len_utf8()];
+                cells.push(
+                    cell(c).ok_or_else(|| ParseError::new(found, expected).within(input, found))?,
+                );
+                row_width += 1;
+            }
+            if *width.get_or_insert(row_width) != row_width {
+                let width = width.unwrap_or_default();
+                return Err(
+                    ParseError::new(line, format!("a row of {width} cells")).within(input, line)
+                );
+            }
+        }
+        Ok(Self {
+            width: width.unwrap_or(0),
+            height,
+            cells,
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, pos: Pos) -> bool {
+        pos.x < self.width && pos.y < self.height
+    }
+
+    pub fn get(&self, pos: Pos) -> Option<&T> {
+        self.contains(pos)
+            .then(|| &self.cells[pos.y * self.width + pos.x])
+    }
+
+    pub fn get_mut(&mut self, pos: Pos) -> Option<&mut T> {
+        self.contains(pos)
+            .then(|| &mut self.cells[pos.y * self.width + pos.x])
+    }
+
+    /// Where `step` leads from `pos`, if that's still in the grid.
+    pub fn step(&self, pos: Pos, step: Vec2<isize>) -> Option<Pos> {
+        let pos = pos.checked_add_signed(step)?;
+        self.contains(pos).then_some(pos)
+    }
+
+    /// The up to four cells next to `pos`, clockwise from the one above.
+    pub fn neighbours(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        ORTHOGONAL
+            .into_iter()
+            .filter_map(move |step| self.step(pos, step))
+    }
+
+    /// The up to eight cells around `pos`, diagonals included, clockwise from the
+    /// one above.
+    pub fn neighbours_diagonal(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        AROUND
+            .into_iter()
+            .filter_map(move |step| self.step(pos, step))
+    }
+
+    /// The cells met going from `pos`, excluded, by `step` until the edge.
+    pub fn ray(&self, pos: Pos, step: Vec2<isize>) -> impl Iterator<Item = Pos> + '_ {
+        std::iter::successors(self.step(pos, step), move |&pos| self.step(pos, step))
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        let (width, height) = (self.width, self.height);
+        assert!(
+            y < height,
+            "row {y} is out of a grid of {width} by {height}"
+        );
+        &self.cells[y * width..(y + 1) * width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        (0..self.height).map(|y| self.row(y))
+    }
+
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        let (width, height) = (self.width, self.height);
+        assert!(
+            x < width,
+            "column {x} is out of a grid of {width} by {height}"
+        );
+        self.cells.iter().skip(x).step_by(width)
+    }
+
+    /// Every position, row by row.
+    pub fn positions(&self) -> impl Iterator<Item = Pos> {
+        let width = self.width;
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| Point2::new(x, y)))
+    }
+
+    /// Every cell with its position, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = (Pos, &T)> {
+        self.positions().zip(&self.cells)
+    }
+
+    /// Where the first cell, row by row, matching `predicate` is.
+    pub fn position(&self, predicate: impl FnMut(&T) -> bool) -> Option<Pos> {
+        let i = self.cells.iter().position(predicate)?;
+        Some(Point2::new(i % self.width, i / self.width))
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+}
+
+impl<T> Index<Pos> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pos: Pos) -> &T {
+        let (width, height) = (self.width, self.height);
+        self.get(pos)
+            .unwrap_or_else(|| panic!("{pos:?} is out of a grid of {width} by {height}"))
+    }
+}
+
+impl<T> IndexMut<Pos> for Grid<T> {
+    fn index_mut(&mut self, pos: Pos) -> &mut T {
+        let (width, height) = (self.width, self.height);
+        self.get_mut(pos)
+            .unwrap_or_else(|| panic!("{pos:?} is out of a grid of {width} by {height}"))
+    }
+}
+
+/// Each cell rendered in turn, one line per row.
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (y, row) in self.rows().enumerate() {
+            if y > 0 {
+                f.write_str("\n")?;
+            }
+            for cell in row {
+                cell.fmt(f)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Grid;
+    use crate::{Point2, Vec2};
+
+    const DIGITS: &str = "123\n456\n";
+
+    fn digits() -> Grid<u32> {
+        Grid::from_chars(DIGITS, "a digit", |c| c.to_digit(10)).unwrap()
+    }
+
+    #[test]
+    fn from_chars() {
+        let grid = digits();
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid[Point2::new(2, 1)], 6);
+        assert_eq!(grid.get(Point2::new(3, 0)), None);
+        assert_eq!(grid.to_string(), DIGITS.trim_end());
+
+        let err = Grid::from_chars("123\n4x6\n", "a digit", |c| c.to_digit(10)).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 2, column 2: expected a digit, found \"x\""
+        );
+
+        let err = Grid::from_chars("123\n45\n", "a digit", |c| c.to_digit(10)).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 2, column 1: expected a row of 3 cells, found \"45\""
+        );
+    }
+
+    #[test]
+    fn neighbours() {
+        let grid = digits();
+        let around = |pos| {
+            grid.neighbours(pos)
+                .map(|pos| grid[pos])
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(around(Point2::new(0, 0)), vec![2, 4]);
+        assert_eq!(around(Point2::new(1, 1)), vec![2, 6, 4]);
+
+        let around = |pos| {
+            grid.neighbours_diagonal(pos)
+                .map(|pos| grid[pos])
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(around(Point2::new(1, 0)), vec![3, 6, 5, 4, 1]);
+    }
+
+    #[test]
+    fn lines() {
+        let grid = digits();
+        assert_eq!(grid.row(1), [4, 5, 6]);
+        assert_eq!(grid.rows().count(), 2);
+        assert_eq!(grid.column(1).copied().collect::<Vec<_>>(), vec![2, 5]);
+        assert_eq!(
+            grid.ray(Point2::new(0, 1), Vec2::new(1, -1))
+                .collect::<Vec<_>>(),
+            vec![Point2::new(1, 0)]
+        );
+        assert_eq!(grid.ray(Point2::new(0, 0), Vec2::new(-1, 0)).count(), 0);
+        assert_eq!(grid.position(|&digit| digit > 4), Some(Point2::new(1, 1)));
+        assert_eq!(
+            grid.iter()
+                .map(|(pos, &digit)| pos.x + pos.y + digit as usize)
+                .sum::<usize>(),
+            21 + 9
+        );
+    }
+
+    #[test]
+    fn without_columns() {
+        let grid = Grid::filled(0, 6, false);
+        assert_eq!((grid.width(), grid.height()), (0, 6));
+        assert_eq!(grid.rows().count(), 6);
+        assert_eq!(grid.map(|&cell| !cell).height(), 6);
+
+        let grid = Grid::from_chars("\n\n", "a digit", |c| c.to_digit(10)).unwrap();
+        assert_eq!((grid.width(), grid.height()), (0, 2));
+    }
+
+    #[test]
+    #[should_panic(expected = "column 3 is out of a grid of 3 by 2")]
+    fn column_out_of_the_grid() {
+        digits().column(3).count();
+    }
+
+    #[test]
+    #[should_panic(expected = "row 2 is out of a grid of 3 by 2")]
+    fn row_out_of_the_grid() {
+        digits().row(2);
+    }
+}
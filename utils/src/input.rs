@@ -0,0 +1,92 @@
+use std::{
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+use anyhow::Context;
+
+use crate::Part;
+
+/// Where the input of a puzzle is read from.
+#[derive(Debug, Clone)]
+pub enum InputSource {
+    /// The input itself, e.g. everything that was piped on stdin.
+    Text(String),
+    /// A single file, used for both parts.
+    File(PathBuf),
+    /// A directory with one `dayN` directory per day, each holding either an `input`
+    /// file or `inputs/partN` files, part 2 falling back to `inputs/part1`.
+    Dir(PathBuf),
+}
+
+impl InputSource {
+    /// `-` reads stdin, anything else is a file.
+    pub fn from_arg(arg: &Path) -> anyhow::Result<Self> {
+        if arg == Path::new("-") {
+            let text = io::read_to_string(io::stdin()).context("can't read stdin")?;
+            Ok(InputSource::Text(text))
+        } else {
+            Ok(InputSource::File(arg.to_path_buf()))
+        }
+    }
+
+    pub fn read(&self, day: u8, part: Part) -> anyhow::Result<String> {
+        let path = match self {
+            InputSource::Text(text) => return Ok(text.clone()),
+            InputSource::File(path) => path.clone(),
+            InputSource::Dir(dir) => find_input(dir, day, part)?,
+        };
+        fs::read_to_string(&path).with_context(|| format!("can't read {}", path.display()))
+    }
+}
+
+fn find_input(dir: &Path, day: u8, part: Part) -> anyhow::Result<PathBuf> {
+    let day_dir = dir.join(format!("day{day}"));
+    let mut candidates = vec![day_dir.join("inputs").join(part.to_string())];
+    if part == Part::Two {
+        candidates.push(day_dir.join("inputs").join(Part::One.to_string()));
+    }
+    candidates.push(day_dir.join("input"));
+
+    candidates
+        .iter()
+        .find(|path| path.is_file())
+        .cloned()
+        .with_context(|| {
+            let tried: Vec<String> = candidates
+                .iter()
+                .map(|path| path.display().to_string())
+                .collect();
+            format!("no input for day {day} {part}, tried {}", tried.join(", "))
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{env, fs};
+
+    use super::InputSource;
+    use crate::Part;
+
+    #[test]
+    fn both_layouts() {
+        let dir = env::temp_dir().join(format!("aoc-utils-input-{}", std::process::id()));
+        fs::create_dir_all(dir.join("day1")).unwrap();
+        fs::write(dir.join("day1/input"), "one").unwrap();
+        fs::create_dir_all(dir.join("day2/inputs")).unwrap();
+        fs::write(dir.join("day2/inputs/part1"), "two").unwrap();
+
+        let source = InputSource::Dir(dir.clone());
+        assert_eq!(source.read(1, Part::One).unwrap(), "one");
+        assert_eq!(source.read(1, Part::Two).unwrap(), "one");
+        assert_eq!(source.read(2, Part::One).unwrap(), "two");
+        assert_eq!(source.read(2, Part::Two).unwrap(), "two");
+        fs::write(dir.join("day2/inputs/part2"), "three").unwrap();
+        assert_eq!(source.read(2, Part::Two).unwrap(), "three");
+
+        let err = source.read(3, Part::Two).unwrap_err().to_string();
+        assert!(err.starts_with("no input for day 3 part2, tried "));
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
@@ -0,0 +1,230 @@
+use std::ops::RangeInclusive;
+
+use crate::Coordinate;
+
+/// A set of integers kept as the disjoint ranges covering them.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct IntervalSet<T> {
+    /// Sorted, neither overlapping nor touching, each `(start, end)` inclusive.
+    ranges: Vec<(T, T)>,
+}
+
+impl<T: Coordinate + From<u8>> IntervalSet<T> {
+    pub fn new() -> Self {
+        Self { ranges: vec![] }
+    }
+
+    /// Adds `range`, merging it with the ranges it overlaps or touches.
+    pub fn insert(&mut self, range: RangeInclusive<T>) {
+        let (mut start, mut end) = range.into_inner();
+        if start > end {
+            return;
+        }
+        let one = T::from(1);
+        // Neither overlapping nor touching, checked without stepping past the
+        // bounds of `T`.
+        let from = self
+            .ranges
+            .partition_point(|&(_, e)| e < start && e + one < start);
+        let to = self
+            .ranges
+            .partition_point(|&(s, _)| s <= end || s - one == end);
+        if from < to {
+            start = start.min(self.ranges[from].0);
+            end = end.max(self.ranges[to - 1].1);
+        }
+        self.ranges.splice(from..to, [(start, end)]);
+    }
+
+    pub fn union(&self, other: &Self) -> Self {
+        let mut union = self.clone();
+        for range in other.ranges() {
+            union.insert(range);
+        }
+        union
+    }
+
+    pub fn intersection(&self, other: &Self) -> Self {
+        let mut ranges = vec![];
+        let (mut i, mut j) = (0, 0);
+        while let (Some(&(a_start, a_end)), Some(&(b_start, b_end))) =
+            (self.ranges.get(i), other.ranges.get(j))
+        {
+            let (start, end) = (a_start.max(b_start), a_end.min(b_end));
+            if start <= end {
+                ranges.push((start, end));
+            }
+            if a_end < b_end {
+                i += 1;
+            } else {
+                j += 1;
+            }
+        }
+        Self { ranges }
+    }
+
+    /// The values of `self` that aren't in `other`.
+    pub fn difference(&self, other: &Self) -> Self {
+        let one = T::from(1);
+        let mut ranges = vec![];
+        for &(start, end) in &self.ranges {
+            let mut start = Some(start);
+            for &(cut_start, cut_end) in &other.ranges {
+                let Some(from) = start else { break };
+                if cut_end < from {
+                    continue;
+                }
+                if cut_start > end {
+                    break;
+                }
+                if cut_start > from {
+                    ranges.push((from, cut_start - one));
+                }
+                start = (cut_end < end).then(|| cut_end + one);
+            }
+            if let Some(from) = start {
+                ranges.push((from, end));
+            }
+        }
+        Self { ranges }
+    }
+
+    pub fn is_superset(&self, other: &Self) -> bool {
+        other.difference(self).is_empty()
+    }
+
+    pub fn contains(&self, value: T) -> bool {
+        let i = self.ranges.partition_point(|&(_, end)| end < value);
+        self.ranges.get(i).is_some_and(|&(start, _)| start <= value)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+
+    /// How many values the set covers, counted wider than `T` since a range can
+    /// hold more values than `T` goes up to.
+    pub fn len(&self) -> u128
+    where
+        T: TryInto<i128>,
+    {
+        let wide = |value: T| value.try_into().ok().expect("coordinates fit in an i128");
+        self.ranges
+            .iter()
+            .map(|&(start, end)| (wide(end) - wide(start)) as u128 + 1)
+            .sum()
+    }
+
+    pub fn min(&self) -> Option<T> {
+        self.ranges.first().map(|&(start, _)| start)
+    }
+
+    pub fn max(&self) -> Option<T> {
+        self.ranges.last().map(|&(_, end)| end)
+    }
+
+    /// The disjoint ranges of the set, in order.
+    pub fn ranges(&self) -> impl Iterator<Item = RangeInclusive<T>> + '_ {
+        self.ranges.iter().map(|&(start, end)| start..=end)
+    }
+
+    /// The ranges missing between the lowest and the highest values, in order.
+    pub fn gaps(&self) -> impl Iterator<Item = RangeInclusive<T>> + '_ {
+        let one = T::from(1);
+        self.ranges
+            .windows(2)
+            .map(move |pair| pair[0].1 + one..=pair[1].0 - one)
+    }
+}
+
+impl<T: Coordinate + From<u8>> From<RangeInclusive<T>> for IntervalSet<T> {
+    fn from(range: RangeInclusive<T>) -> Self {
+        let mut set = Self::new();
+        set.insert(range);
+        set
+    }
+}
+
+impl<T: Coordinate + From<u8>> FromIterator<RangeInclusive<T>> for IntervalSet<T> {
+    fn from_iter<I: IntoIterator<Item = RangeInclusive<T>>>(ranges: I) -> Self {
+        let mut set = Self::new();
+        for range in ranges {
+            set.insert(range);
+        }
+        set
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::IntervalSet;
+
+    fn set(ranges: &[(i32, i32)]) -> IntervalSet<i32> {
+        ranges.iter().map(|&(start, end)| start..=end).collect()
+    }
+
+    #[test]
+    fn insert() {
+        let mut covered = set(&[(5, 7), (1, 2)]);
+        assert_eq!(covered.ranges().collect::<Vec<_>>(), vec![1..=2, 5..=7]);
+
+        covered.insert(3..=3);
+        assert_eq!(covered.ranges().collect::<Vec<_>>(), vec![1..=3, 5..=7]);
+        covered.insert(4..=4);
+        assert_eq!(covered, set(&[(1, 7)]));
+        covered.insert(-3..=-1);
+        let (start, end) = (9, 8);
+        covered.insert(start..=end);
+        assert_eq!(covered, set(&[(-3, -1), (1, 7)]));
+        covered.insert(-10..=20);
+        assert_eq!(covered, set(&[(-10, 20)]));
+    }
+
+    #[test]
+    fn bounds() {
+        let mut covered = set(&[(i32::MAX - 2, i32::MAX)]);
+        covered.insert(i32::MAX - 5..=i32::MAX - 3);
+        covered.insert(i32::MIN..=i32::MIN + 1);
+        covered.insert(i32::MIN + 2..=i32::MIN + 2);
+        assert_eq!(
+            covered,
+            set(&[(i32::MIN, i32::MIN + 2), (i32::MAX - 5, i32::MAX)])
+        );
+        covered.insert(i32::MAX..=i32::MAX);
+        assert_eq!(covered.max(), Some(i32::MAX));
+        assert_eq!(
+            covered.difference(&set(&[(i32::MIN, 0), (i32::MAX, i32::MAX)])),
+            set(&[(i32::MAX - 5, i32::MAX - 1)])
+        );
+        assert_eq!(
+            covered.gaps().collect::<Vec<_>>(),
+            vec![i32::MIN + 3..=i32::MAX - 6]
+        );
+        assert_eq!(set(&[(i32::MIN, 0)]).len(), 1 << 31 | 1);
+        assert_eq!(IntervalSet::from(0..=u64::MAX).len(), u64::MAX as u128 + 1);
+    }
+
+    #[test]
+    fn operations() {
+        let a = set(&[(0, 4), (8, 10)]);
+        let b = set(&[(3, 9), (12, 12)]);
+        assert_eq!(a.union(&b), set(&[(0, 10), (12, 12)]));
+        assert_eq!(a.intersection(&b), set(&[(3, 4), (8, 9)]));
+        assert_eq!(a.difference(&b), set(&[(0, 2), (10, 10)]));
+        assert_eq!(b.difference(&a), set(&[(5, 7), (12, 12)]));
+        assert!(a.difference(&set(&[(-1, 11)])).is_empty());
+        assert!(a.is_superset(&set(&[(1, 3), (9, 9)])));
+        assert!(!a.is_superset(&b));
+    }
+
+    #[test]
+    fn queries() {
+        let covered = set(&[(0, 4), (8, 10), (14, 20)]);
+        assert_eq!(covered.len(), 5 + 3 + 7);
+        assert!(covered.contains(0) && covered.contains(9) && covered.contains(20));
+        assert!(!covered.contains(-1) && !covered.contains(5) && !covered.contains(21));
+        assert_eq!(covered.gaps().collect::<Vec<_>>(), vec![5..=7, 11..=13]);
+        assert_eq!((covered.min(), covered.max()), (Some(0), Some(20)));
+        assert_eq!(IntervalSet::<usize>::new().len(), 0);
+    }
+}
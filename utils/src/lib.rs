@@ -0,0 +1,20 @@
+pub mod answers;
+mod grid;
+mod input;
+mod interval;
+mod ocr;
+mod parse;
+mod point;
+mod registry;
+mod search;
+mod solution;
+
+pub use grid::{Grid, Pos, AROUND, ORTHOGONAL};
+pub use input::InputSource;
+pub use interval::IntervalSet;
+pub use ocr::read_letters;
+pub use parse::{numbers, parse_lines, parse_number, parse_within, ParseError};
+pub use point::{Coordinate, Direction, Point2, Point3, Vec2};
+pub use registry::{Day, Registry};
+pub use search::{astar, bfs, dijkstra, multi_bfs, Paths};
+pub use solution::{Answer, Part, Puzzle, Solution, Unsolved};
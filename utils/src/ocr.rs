@@ -0,0 +1,103 @@
+use crate::{Grid, Point2};
+
+/// The letters puzzles draw, in the order of [`FONT`].
+const LETTERS: &str = "ABCEFGHIJKLOPRSUYZ";
+
+/// Each letter 4 pixels wide and 6 high, followed by a blank column.
+const FONT: [&str; 6] = [
+    ".##..###...##..####.####..##..#..#..###...##.#..#.#.....##..###..###...###.#..#.#....####",
+    "#..#.#..#.#..#.#....#....#..#.#..#...#.....#.#.#..#....#..#.#..#.#..#.#....#..#.#.......#",
+    "#..#.###..#....###..###..#....####...#.....#.##...#....#..#.#..#.#..#.#....#..#..#.#...#.",
+    "####.#..#.#....#....#....#.##.#..#...#.....#.#.#..#....#..#.###..###...##..#..#...#...#..",
+    "#..#.#..#.#..#.#....#....#..#.#..#...#..#..#.#.#..#....#..#.#....#.#.....#.#..#...#..#...",
+    "#..#.###...##..####.#.....###.#..#..###..##..#..#.####..##..#....#..#.###...##....#..####",
+];
+
+const WIDTH: usize = 4;
+const HEIGHT: usize = FONT.len();
+
+/// The letters `pixels` show in the font of the puzzles, lit pixels being
+/// `true`, unless it shows anything else.
+pub fn read_letters(pixels: &Grid<bool>) -> Option<String> {
+    // The blank column after the last letter may be left out.
+    let width = pixels.width() + 1;
+    if pixels.height() != HEIGHT || width < WIDTH + 1 || width % (WIDTH + 1) > 1 {
+        return None;
+    }
+    // Letters must be apart, not just happen to line up.
+    let blank = (WIDTH..pixels.width())
+        .step_by(WIDTH + 1)
+        .all(|x| (0..HEIGHT).all(|y| !pixels[Point2::new(x, y)]));
+    if !blank {
+        return None;
+    }
+    (0..width / (WIDTH + 1))
+        .map(|i| letter(pixels, i * (WIDTH + 1)))
+        .collect()
+}
+
+/// The letter drawn from the column `left` of `pixels`.
+fn letter(pixels: &Grid<bool>, left: usize) -> Option<char> {
+    LETTERS.chars().enumerate().find_map(|(i, letter)| {
+        let matches = FONT.iter().enumerate().all(|(y, row)| {
+            row[i * (WIDTH + 1)..][..WIDTH]
+                .bytes()
+                .enumerate()
+                .all(|(x, pixel)| (pixel == b'#') == pixels[Point2::new(left + x, y)])
+        });
+        matches.then_some(letter)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{read_letters, FONT, HEIGHT, LETTERS, WIDTH};
+    use crate::Grid;
+
+    fn pixels(drawing: &str) -> Grid<bool> {
+        Grid::from_chars(drawing, "# or .", |c| match c {
+            '#' => Some(true),
+            '.' => Some(false),
+            _ => None,
+        })
+        .unwrap()
+    }
+
+    #[test]
+    fn font() {
+        assert_eq!(
+            read_letters(&pixels(&FONT.join("\n"))).as_deref(),
+            Some(LETTERS)
+        );
+    }
+
+    #[test]
+    fn words() {
+        let drawing = "\
+#..#..###
+#..#...#.
+####...#.
+#..#...#.
+#..#...#.
+#..#..###";
+        assert_eq!(read_letters(&pixels(drawing)).as_deref(), Some("HI"));
+        let spaced = drawing.replace('\n', ".\n") + ".";
+        assert_eq!(read_letters(&pixels(&spaced)).as_deref(), Some("HI"));
+
+        // Not a letter, or not the height of one.
+        assert_eq!(
+            read_letters(&pixels(&drawing.replace("####", "#.##"))),
+            None
+        );
+        assert_eq!(read_letters(&pixels(&drawing[..drawing.len() - 10])), None);
+
+        // Nothing to read, or something between letters.
+        for width in [0, WIDTH - 1] {
+            let narrow = Grid::filled(width, HEIGHT, false);
+            assert_eq!(narrow.height(), HEIGHT);
+            assert_eq!(read_letters(&narrow), None);
+        }
+        let joined = spaced.replacen("#..#..###", "#..##.###", 1);
+        assert_eq!(read_letters(&pixels(&joined)), None);
+    }
+}
@@ -0,0 +1,194 @@
+use std::{
+    error::Error,
+    fmt::{self, Display},
+    str::FromStr,
+};
+
+/// Where and why some input couldn't be parsed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    /// Starting at 1.
+    pub line: usize,
+    /// Starting at 1, in characters.
+    pub column: usize,
+    pub expected: String,
+    pub found: String,
+}
+
+impl ParseError {
+    /// An error at the start of `found`; use [`ParseError::within`] to place it in
+    /// the bigger input `found` was cut from.
+    pub fn new(found: &str, expected: impl Into<String>) -> Self {
+        Self {
+            line: 1,
+            column: 1,
+            expected: expected.into(),
+            found: found.to_string(),
+        }
+    }
+
+    /// Moves an error of `inner`, a slice of `outer`, to where it is in `outer`.
+    pub fn within(mut self, outer: &str, inner: &str) -> Self {
+        let outer_start = outer.as_ptr() as usize;
+        let inner_start = inner.as_ptr() as usize;
+        if inner_start < outer_start || inner_start + inner.len() > outer_start + outer.len() {
+            return self;
+        }
+
+        let before = &outer[..inner_start - outer_start];
+        let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+        if self.line == 1 {
+            self.column += before[line_start..].chars().count();
+        }
+        self.line += before.matches('\n').count();
+        self
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "line {}, column {}: expected {}, found ",
+            self.line, self.column, self.expected
+        )?;
+        match self.found.as_str() {
+            "" => f.write_str("nothing"),
+            found => write!(f, "{found:?}"),
+        }
+    }
+}
+
+impl Error for ParseError {}
+
+/// Parses `inner`, a slice of `outer`, with errors placed in `outer`.
+pub fn parse_within<T: FromStr<Err = ParseError>>(
+    outer: &str,
+    inner: &str,
+) -> Result<T, ParseError> {
+    inner
+        .parse()
+        .map_err(|err: ParseError| err.within(outer, inner))
+}
+
+/// Parses every line of `input`.
+pub fn parse_lines<T: FromStr<Err = ParseError>>(input: &str) -> Result<Vec<T>, ParseError> {
+    input
+        .lines()
+        .map(|line| parse_within(input, line))
+        .collect()
+}
+
+/// Parses `token`, a slice of `outer`, as a number.
+pub fn parse_number<T: FromStr>(outer: &str, token: &str) -> Result<T, ParseError> {
+    token
+        .parse()
+        .map_err(|_| ParseError::new(token, "a number").within(outer, token))
+}
+
+/// Every integer in `s`, in order, whatever surrounds them.
+///
+/// A `-` right before digits makes a negative number, unless it follows another
+/// number, so that `2-4` is a range of two numbers. Numbers that don't fit in `T`
+/// are errors, placed in `s`.
+pub fn numbers<T: FromStr>(s: &str) -> impl Iterator<Item = Result<T, ParseError>> + '_ {
+    let bytes = s.as_bytes();
+    let mut i = 0;
+
+    std::iter::from_fn(move || {
+        while i < bytes.len() {
+            let start = i;
+            let negative = bytes[i] == b'-'
+                && bytes.get(i + 1).is_some_and(u8::is_ascii_digit)
+                && (i == 0 || !bytes[i - 1].is_ascii_digit());
+            if negative {
+                i += 1;
+            }
+            if !bytes[i].is_ascii_digit() {
+                i += 1;
+                continue;
+            }
+            while i < bytes.len() && bytes[i].is_ascii_digit() {
+                i += 1;
+            }
+            return Some(parse_number(s, &s[start..i]));
+        }
+        None
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use super::{numbers, parse_lines, parse_number, ParseError};
+
+    #[derive(Debug)]
+    struct Pair(u8, u8);
+
+    impl FromStr for Pair {
+        type Err = ParseError;
+
+        fn from_str(s: &str) -> Result<Self, Self::Err> {
+            let (left, right) = s
+                .split_once(' ')
+                .ok_or_else(|| ParseError::new(s, "two numbers"))?;
+            Ok(Pair(parse_number(s, left)?, parse_number(s, right)?))
+        }
+    }
+
+    #[test]
+    fn errors_are_placed_in_the_whole_input() {
+        let pairs = parse_lines::<Pair>("1 2\n3 4\n").unwrap();
+        assert_eq!(pairs.iter().map(|pair| pair.0 * pair.1).sum::<u8>(), 14);
+
+        let err = parse_lines::<Pair>("1 2\n3 4\n5 x6\n").unwrap_err();
+        assert_eq!((err.line, err.column), (3, 3));
+        assert_eq!(
+            err.to_string(),
+            "line 3, column 3: expected a number, found \"x6\""
+        );
+
+        let err = parse_lines::<Pair>("1 2\n\n").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 2, column 1: expected two numbers, found nothing"
+        );
+    }
+
+    #[test]
+    fn signed() {
+        let line = "Sensor at x=2, y=-18: closest beacon is at x=-2, y=15";
+        assert_eq!(
+            numbers::<i64>(line).collect::<Result<Vec<_>, _>>(),
+            Ok(vec![2, -18, -2, 15])
+        );
+    }
+
+    #[test]
+    fn ranges() {
+        assert_eq!(
+            numbers::<u32>("2-4,6-8").collect::<Result<Vec<_>, _>>(),
+            Ok(vec![2, 4, 6, 8])
+        );
+        assert_eq!(numbers::<u8>("no numbers -").count(), 0);
+    }
+
+    #[test]
+    fn out_of_range() {
+        let found: Vec<String> = numbers::<u8>("move 1 from 300 to -3")
+            .map(|number| match number {
+                Ok(number) => number.to_string(),
+                Err(err) => err.to_string(),
+            })
+            .collect();
+        assert_eq!(
+            found,
+            vec![
+                "1",
+                "line 1, column 13: expected a number, found \"300\"",
+                "line 1, column 20: expected a number, found \"-3\"",
+            ]
+        );
+    }
+}
@@ -0,0 +1,352 @@
+use std::{
+    ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign},
+    str::FromStr,
+};
+
+use crate::{parse_number, ParseError};
+
+/// The integers points and vectors are made of.
+pub trait Coordinate:
+    Copy + Ord + Default + Add<Output = Self> + Sub<Output = Self> + Mul<Output = Self>
+{
+    /// How far apart `self` and `other` are, whichever is the biggest.
+    fn distance(self, other: Self) -> Self {
+        if self > other {
+            self - other
+        } else {
+            other - self
+        }
+    }
+}
+
+impl Coordinate for i32 {}
+impl Coordinate for i64 {}
+impl Coordinate for isize {}
+impl Coordinate for u32 {}
+impl Coordinate for u64 {}
+impl Coordinate for usize {}
+
+/// A position on a plane. On grids `y` grows downwards, as lines do.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Point2<T> {
+    pub x: T,
+    pub y: T,
+}
+
+/// A move on a plane, from one [`Point2`] to another.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Vec2<T> {
+    pub x: T,
+    pub y: T,
+}
+
+/// A position in space.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Point3<T> {
+    pub x: T,
+    pub y: T,
+    pub z: T,
+}
+
+impl<T> Point2<T> {
+    pub const fn new(x: T, y: T) -> Self {
+        Self { x, y }
+    }
+}
+
+impl<T: Coordinate> Point2<T> {
+    /// The length of the shortest path to `other` going only up, down, left or right.
+    pub fn manhattan(self, other: Self) -> T {
+        self.x.distance(other.x) + self.y.distance(other.y)
+    }
+
+    /// The number of moves to `other` when moving diagonally counts as one.
+    pub fn chebyshev(self, other: Self) -> T {
+        self.x.distance(other.x).max(self.y.distance(other.y))
+    }
+}
+
+impl Point2<usize> {
+    /// Where `v` leads, unless it is left or above the origin.
+    pub fn checked_add_signed(self, v: Vec2<isize>) -> Option<Self> {
+        Some(Self::new(
+            self.x.checked_add_signed(v.x)?,
+            self.y.checked_add_signed(v.y)?,
+        ))
+    }
+}
+
+impl<T> From<(T, T)> for Point2<T> {
+    fn from((x, y): (T, T)) -> Self {
+        Self::new(x, y)
+    }
+}
+
+/// `x,y`, the way most puzzles write coordinates.
+impl<T: FromStr> FromStr for Point2<T> {
+    type Err = ParseError;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let (x, y) = input
+            .split_once(',')
+            .ok_or_else(|| ParseError::new(input, "x,y coordinates"))?;
+        Ok(Self::new(parse_number(input, x)?, parse_number(input, y)?))
+    }
+}
+
+impl TryFrom<Point2<isize>> for Point2<usize> {
+    type Error = std::num::TryFromIntError;
+
+    fn try_from(p: Point2<isize>) -> Result<Self, Self::Error> {
+        Ok(Self::new(p.x.try_into()?, p.y.try_into()?))
+    }
+}
+
+impl TryFrom<Point2<usize>> for Point2<isize> {
+    type Error = std::num::TryFromIntError;
+
+    fn try_from(p: Point2<usize>) -> Result<Self, Self::Error> {
+        Ok(Self::new(p.x.try_into()?, p.y.try_into()?))
+    }
+}
+
+impl<T: Coordinate> Add<Vec2<T>> for Point2<T> {
+    type Output = Self;
+
+    fn add(self, v: Vec2<T>) -> Self {
+        Self::new(self.x + v.x, self.y + v.y)
+    }
+}
+
+impl<T: Coordinate> AddAssign<Vec2<T>> for Point2<T> {
+    fn add_assign(&mut self, v: Vec2<T>) {
+        *self = *self + v;
+    }
+}
+
+impl<T: Coordinate> Sub<Vec2<T>> for Point2<T> {
+    type Output = Self;
+
+    fn sub(self, v: Vec2<T>) -> Self {
+        Self::new(self.x - v.x, self.y - v.y)
+    }
+}
+
+impl<T: Coordinate> SubAssign<Vec2<T>> for Point2<T> {
+    fn sub_assign(&mut self, v: Vec2<T>) {
+        *self = *self - v;
+    }
+}
+
+impl<T: Coordinate> Sub for Point2<T> {
+    type Output = Vec2<T>;
+
+    fn sub(self, other: Self) -> Vec2<T> {
+        Vec2::new(self.x - other.x, self.y - other.y)
+    }
+}
+
+impl<T> Vec2<T> {
+    pub const fn new(x: T, y: T) -> Self {
+        Self { x, y }
+    }
+}
+
+impl<T: Coordinate> Vec2<T> {
+    /// How many steps up, down, left or right it takes.
+    pub fn manhattan(self) -> T {
+        self.x.distance(T::default()) + self.y.distance(T::default())
+    }
+
+    /// How many steps it takes when diagonal steps count as one.
+    pub fn chebyshev(self) -> T {
+        self.x
+            .distance(T::default())
+            .max(self.y.distance(T::default()))
+    }
+}
+
+impl Vec2<isize> {
+    /// The same move cut down to at most one step on each axis.
+    pub fn signum(self) -> Self {
+        Self::new(self.x.signum(), self.y.signum())
+    }
+}
+
+impl<T: Coordinate> Add for Vec2<T> {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Self::new(self.x + other.x, self.y + other.y)
+    }
+}
+
+impl<T: Coordinate> Sub for Vec2<T> {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        Self::new(self.x - other.x, self.y - other.y)
+    }
+}
+
+impl<T: Coordinate> Mul<T> for Vec2<T> {
+    type Output = Self;
+
+    fn mul(self, n: T) -> Self {
+        Self::new(self.x * n, self.y * n)
+    }
+}
+
+impl<T: Neg<Output = T>> Neg for Vec2<T> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self::new(-self.x, -self.y)
+    }
+}
+
+impl<T> Point3<T> {
+    pub const fn new(x: T, y: T, z: T) -> Self {
+        Self { x, y, z }
+    }
+}
+
+impl<T: Coordinate> Point3<T> {
+    pub fn manhattan(self, other: Self) -> T {
+        self.x.distance(other.x) + self.y.distance(other.y) + self.z.distance(other.z)
+    }
+
+    pub fn chebyshev(self, other: Self) -> T {
+        self.x
+            .distance(other.x)
+            .max(self.y.distance(other.y))
+            .max(self.z.distance(other.z))
+    }
+}
+
+/// Points in space are added and subtracted component by component.
+impl<T: Coordinate> Add for Point3<T> {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Self::new(self.x + other.x, self.y + other.y, self.z + other.z)
+    }
+}
+
+impl<T: Coordinate> Sub for Point3<T> {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        Self::new(self.x - other.x, self.y - other.y, self.z - other.z)
+    }
+}
+
+/// One of the four orthogonal directions on a grid, `y` growing downwards.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Direction {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+impl Direction {
+    /// Clockwise from up.
+    pub const ALL: [Direction; 4] = [
+        Direction::Up,
+        Direction::Right,
+        Direction::Down,
+        Direction::Left,
+    ];
+
+    /// The move of one step this way.
+    pub const fn step(self) -> Vec2<isize> {
+        match self {
+            Direction::Up => Vec2::new(0, -1),
+            Direction::Right => Vec2::new(1, 0),
+            Direction::Down => Vec2::new(0, 1),
+            Direction::Left => Vec2::new(-1, 0),
+        }
+    }
+
+    pub fn turn_right(self) -> Self {
+        Self::ALL[(self as usize + 1) % 4]
+    }
+
+    pub fn turn_left(self) -> Self {
+        Self::ALL[(self as usize + 3) % 4]
+    }
+
+    pub fn opposite(self) -> Self {
+        Self::ALL[(self as usize + 2) % 4]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Direction, Point2, Point3, Vec2};
+
+    #[test]
+    fn arithmetic() {
+        let p = Point2::new(3, 4);
+        assert_eq!(p + Vec2::new(-1, 2), Point2::new(2, 6));
+        assert_eq!(p - Point2::new(1, 1), Vec2::new(2, 3));
+        assert_eq!(-Vec2::new(1, -2) * 3, Vec2::new(-3, 6));
+        assert_eq!(Vec2::new(-5, 2).signum(), Vec2::new(-1, 1));
+
+        let mut p = Point2::new(1, 1);
+        p += Vec2::new(2, 2);
+        p -= Vec2::new(1, 0);
+        assert_eq!(p, Point2::new(2, 3));
+    }
+
+    #[test]
+    fn parse() {
+        assert_eq!("498,4".parse(), Ok(Point2::new(498_usize, 4)));
+        assert_eq!(
+            "498".parse::<Point2<usize>>().unwrap_err().to_string(),
+            "line 1, column 1: expected x,y coordinates, found \"498\""
+        );
+        assert_eq!(
+            "-1,4".parse::<Point2<usize>>().unwrap_err().to_string(),
+            "line 1, column 1: expected a number, found \"-1\""
+        );
+    }
+
+    #[test]
+    fn distances() {
+        let (a, b) = (Point2::new(2_usize, 18), Point2::new(9, 16));
+        assert_eq!(a.manhattan(b), 9);
+        assert_eq!(b.chebyshev(a), 7);
+        assert_eq!(Vec2::new(-2, 3).manhattan(), 5);
+        assert_eq!(Vec2::new(-2, 3).chebyshev(), 3);
+
+        let (a, b) = (Point3::new(0, 0, 0), Point3::new(1, -2, 3));
+        assert_eq!(a.manhattan(b), 6);
+        assert_eq!(a.chebyshev(b), 3);
+        assert_eq!(b - b + b, b);
+    }
+
+    #[test]
+    fn signedness() {
+        let p = Point2::new(0_usize, 2);
+        assert_eq!(
+            p.checked_add_signed(Vec2::new(0, -2)),
+            Some(Point2::new(0, 0))
+        );
+        assert_eq!(p.checked_add_signed(Vec2::new(-1, 0)), None);
+        assert_eq!(Point2::<isize>::try_from(p), Ok(Point2::new(0, 2)));
+        assert!(Point2::<usize>::try_from(Point2::new(-1_isize, 0)).is_err());
+    }
+
+    #[test]
+    fn rotations() {
+        assert_eq!(Direction::Up.turn_right(), Direction::Right);
+        assert_eq!(Direction::Up.turn_left(), Direction::Left);
+        assert_eq!(Direction::Left.opposite(), Direction::Right);
+        assert_eq!(Direction::Down.step(), Vec2::new(0, 1));
+        assert!(Direction::ALL
+            .iter()
+            .all(|d| d.step() + d.opposite().step() == Vec2::default()));
+    }
+}
@@ -0,0 +1,118 @@
+use std::collections::BTreeMap;
+
+use crate::solution::{Answer, Parsed, Part, Puzzle, Solution};
+
+type Parser = fn(&str) -> anyhow::Result<Box<dyn Puzzle>>;
+
+fn parse<S: Solution + 'static>(input: &str) -> anyhow::Result<Box<dyn Puzzle>> {
+    Ok(Box::new(Parsed::<S>(S::parse(input)?)))
+}
+
+#[derive(Clone, Copy)]
+pub struct Day {
+    pub year: u16,
+    pub day: u8,
+    parser: Parser,
+}
+
+impl Day {
+    pub fn new<S: Solution + 'static>(year: u16, day: u8) -> Self {
+        Self {
+            year,
+            day,
+            parser: parse::<S>,
+        }
+    }
+
+    pub fn parse(&self, input: &str) -> anyhow::Result<Box<dyn Puzzle>> {
+        (self.parser)(input)
+    }
+
+    pub fn solve(&self, input: &str, part: Part) -> anyhow::Result<Answer> {
+        self.parse(input)?.solve(part)
+    }
+}
+
+impl std::fmt::Debug for Day {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Day")
+            .field("year", &self.year)
+            .field("day", &self.day)
+            .finish()
+    }
+}
+
+/// Every known [`Solution`], indexed by year and day.
+#[derive(Debug, Default)]
+pub struct Registry {
+    days: BTreeMap<(u16, u8), Day>,
+}
+
+impl Registry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn register<S: Solution + 'static>(&mut self, year: u16, day: u8) -> &mut Self {
+        self.days.insert((year, day), Day::new::<S>(year, day));
+        self
+    }
+
+    pub fn get(&self, year: u16, day: u8) -> Option<&Day> {
+        self.days.get(&(year, day))
+    }
+
+    pub fn days(&self) -> impl Iterator<Item = &Day> {
+        self.days.values()
+    }
+
+    pub fn year(&self, year: u16) -> impl Iterator<Item = &Day> {
+        self.days
+            .range((year, u8::MIN)..=(year, u8::MAX))
+            .map(|(_, day)| day)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Registry;
+    use crate::{Answer, Part, Solution, Unsolved};
+
+    struct Length;
+
+    impl Solution for Length {
+        type Input = String;
+
+        fn parse(input: &str) -> anyhow::Result<Self::Input> {
+            Ok(input.to_string())
+        }
+
+        fn part1(input: &Self::Input) -> anyhow::Result<Answer> {
+            Ok(input.len().into())
+        }
+    }
+
+    #[test]
+    fn solve_registered_day() {
+        let mut registry = Registry::new();
+        registry.register::<Length>(2022, 1);
+
+        let day = registry.get(2022, 1).unwrap();
+        assert_eq!(day.solve("abc", Part::One).unwrap().to_string(), "3");
+        assert!(day.solve("abc", Part::Two).unwrap_err().is::<Unsolved>());
+        assert!(registry.get(2022, 2).is_none());
+    }
+
+    #[test]
+    fn days_of_a_year() {
+        let mut registry = Registry::new();
+        registry
+            .register::<Length>(2023, 1)
+            .register::<Length>(2022, 2)
+            .register::<Length>(2022, 1);
+
+        let days: Vec<(u16, u8)> = registry.year(2022).map(|d| (d.year, d.day)).collect();
+        assert_eq!(days, vec![(2022, 1), (2022, 2)]);
+        assert_eq!(registry.days().count(), 3);
+    }
+}
@@ -0,0 +1,276 @@
+use std::{
+    cmp::Ordering,
+    collections::{hash_map::Entry, BinaryHeap, HashMap, VecDeque},
+    hash::Hash,
+    ops::Add,
+};
+
+/// The shortest paths a search found from its start nodes to every node it
+/// reached, costing `C` each.
+#[derive(Debug, Clone)]
+pub struct Paths<N, C = usize> {
+    /// The cost to reach each node, and the node it was reached from.
+    reached: HashMap<N, (C, Option<N>)>,
+}
+
+impl<N: Clone + Eq + Hash, C: Copy> Paths<N, C> {
+    pub fn contains(&self, node: &N) -> bool {
+        self.reached.contains_key(node)
+    }
+
+    /// The cost of the shortest path to `node`, if it was reached.
+    pub fn distance(&self, node: &N) -> Option<C> {
+        self.reached.get(node).map(|&(cost, _)| cost)
+    }
+
+    /// The nodes from a start to `node`, both included.
+    pub fn path_to(&self, node: &N) -> Option<Vec<N>> {
+        let mut path = vec![node.clone()];
+        let mut previous = &self.reached.get(node)?.1;
+        while let Some(node) = previous {
+            path.push(node.clone());
+            previous = &self.reached[node].1;
+        }
+        path.reverse();
+        Some(path)
+    }
+
+    /// The start the shortest path to `node` comes from.
+    pub fn start_of(&self, node: &N) -> Option<N> {
+        let mut node = node;
+        while let Some(previous) = &self.reached.get(node)?.1 {
+            node = previous;
+        }
+        Some(node.clone())
+    }
+
+    /// Every node reached with the cost to reach it, in no particular order.
+    pub fn iter(&self) -> impl Iterator<Item = (&N, C)> {
+        self.reached.iter().map(|(node, &(cost, _))| (node, cost))
+    }
+}
+
+/// Every node reachable from `start`, each step costing 1.
+pub fn bfs<N, I>(start: N, neighbours: impl FnMut(&N) -> I) -> Paths<N>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = N>,
+{
+    multi_bfs([start], neighbours)
+}
+
+/// Every node reachable from any of `starts`, from the closest of them.
+pub fn multi_bfs<N, I>(
+    starts: impl IntoIterator<Item = N>,
+    mut neighbours: impl FnMut(&N) -> I,
+) -> Paths<N>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = N>,
+{
+    let mut reached = HashMap::new();
+    let mut queue = VecDeque::new();
+    for start in starts {
+        if let Entry::Vacant(entry) = reached.entry(start.clone()) {
+            entry.insert((0, None));
+            queue.push_back(start);
+        }
+    }
+
+    while let Some(node) = queue.pop_front() {
+        let distance = reached[&node].0;
+        for next in neighbours(&node) {
+            if let Entry::Vacant(entry) = reached.entry(next.clone()) {
+                entry.insert((distance + 1, Some(node.clone())));
+                queue.push_back(next);
+            }
+        }
+    }
+
+    Paths { reached }
+}
+
+/// Every node reachable from `start`, `neighbours` giving the cost of each step.
+pub fn dijkstra<N, C, I>(start: N, mut neighbours: impl FnMut(&N) -> I) -> Paths<N, C>
+where
+    N: Clone + Eq + Hash,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (N, C)>,
+{
+    let mut reached = HashMap::from([(start.clone(), (C::default(), None))]);
+    let mut queue = BinaryHeap::from([Queued::new(C::default(), C::default(), start)]);
+
+    while let Some(Queued { cost, node, .. }) = queue.pop() {
+        if reached[&node].0 < cost {
+            continue;
+        }
+        for (next, step) in neighbours(&node) {
+            let cost = cost + step;
+            if reached.get(&next).is_none_or(|&(known, _)| cost < known) {
+                reached.insert(next.clone(), (cost, Some(node.clone())));
+                queue.push(Queued::new(cost, cost, next));
+            }
+        }
+    }
+
+    Paths { reached }
+}
+
+/// The shortest path from `start` to a node that `is_goal`, with its cost.
+///
+/// `heuristic` must never overestimate the cost left to a goal.
+pub fn astar<N, C, I>(
+    start: N,
+    mut neighbours: impl FnMut(&N) -> I,
+    mut heuristic: impl FnMut(&N) -> C,
+    mut is_goal: impl FnMut(&N) -> bool,
+) -> Option<(Vec<N>, C)>
+where
+    N: Clone + Eq + Hash,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (N, C)>,
+{
+    let mut paths = Paths {
+        reached: HashMap::from([(start.clone(), (C::default(), None))]),
+    };
+    let mut queue = BinaryHeap::from([Queued::new(heuristic(&start), C::default(), start)]);
+
+    while let Some(Queued { cost, node, .. }) = queue.pop() {
+        if is_goal(&node) {
+            return Some((paths.path_to(&node)?, cost));
+        }
+        if paths.reached[&node].0 < cost {
+            continue;
+        }
+        for (next, step) in neighbours(&node) {
+            let cost = cost + step;
+            if paths.distance(&next).is_none_or(|known| cost < known) {
+                paths
+                    .reached
+                    .insert(next.clone(), (cost, Some(node.clone())));
+                queue.push(Queued::new(cost + heuristic(&next), cost, next));
+            }
+        }
+    }
+
+    None
+}
+
+/// A node waiting in a search's queue, the lowest `priority` first.
+struct Queued<N, C> {
+    priority: C,
+    cost: C,
+    node: N,
+}
+
+impl<N, C> Queued<N, C> {
+    fn new(priority: C, cost: C, node: N) -> Self {
+        Self {
+            priority,
+            cost,
+            node,
+        }
+    }
+}
+
+impl<N, C: Ord> Ord for Queued<N, C> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.priority.cmp(&self.priority)
+    }
+}
+
+impl<N, C: Ord> PartialOrd for Queued<N, C> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<N, C: Ord> PartialEq for Queued<N, C> {
+    fn eq(&self, other: &Self) -> bool {
+        self.priority == other.priority
+    }
+}
+
+impl<N, C: Ord> Eq for Queued<N, C> {}
+
+#[cfg(test)]
+mod tests {
+    use super::{astar, bfs, dijkstra, multi_bfs};
+    use crate::{Grid, Point2};
+
+    /// `#` are walls.
+    const MAZE: &str = "\
+..#....
+.##.##.
+.......
+";
+
+    fn maze() -> Grid<bool> {
+        Grid::from_chars(MAZE, "a wall or not", |c| Some(c == '#')).unwrap()
+    }
+
+    #[test]
+    fn breadth_first() {
+        let maze = maze();
+        let open = |pos: &Point2<usize>| {
+            maze.neighbours(*pos)
+                .filter(|&next| !maze[next])
+                .collect::<Vec<_>>()
+        };
+
+        let paths = bfs(Point2::new(0, 0), open);
+        assert_eq!(paths.distance(&Point2::new(3, 0)), Some(7));
+        assert_eq!(
+            paths.path_to(&Point2::new(1, 2)).unwrap(),
+            [(0, 0), (0, 1), (0, 2), (1, 2)].map(Point2::from)
+        );
+        assert!(!paths.contains(&Point2::new(2, 0)));
+        assert_eq!(paths.iter().count(), 16);
+
+        let paths = multi_bfs([Point2::new(0, 0), Point2::new(6, 0)], open);
+        assert_eq!(paths.distance(&Point2::new(3, 0)), Some(3));
+        assert_eq!(paths.start_of(&Point2::new(4, 2)), Some(Point2::new(6, 0)));
+        assert_eq!(paths.start_of(&Point2::new(1, 2)), Some(Point2::new(0, 0)));
+    }
+
+    #[test]
+    fn weighted() {
+        // a -1- b -1- c, and a -5- c
+        let edges = |node: &char| match node {
+            'a' => vec![('b', 1), ('c', 5)],
+            'b' => vec![('a', 1), ('c', 1)],
+            _ => vec![('a', 5), ('b', 1)],
+        };
+
+        let paths = dijkstra('a', edges);
+        assert_eq!(paths.distance(&'c'), Some(2));
+        assert_eq!(paths.path_to(&'c'), Some(vec!['a', 'b', 'c']));
+
+        assert_eq!(
+            astar('a', edges, |_| 0, |&node| node == 'c'),
+            Some((vec!['a', 'b', 'c'], 2))
+        );
+        assert_eq!(astar('a', edges, |_| 0, |&node| node == 'd'), None);
+    }
+
+    #[test]
+    fn heuristic() {
+        let maze = maze();
+        let goal = Point2::new(3, 0);
+        let (path, cost) = astar(
+            Point2::new(0, 0),
+            |pos| {
+                maze.neighbours(*pos)
+                    .filter(|&next| !maze[next])
+                    .map(|next| (next, 1))
+                    .collect::<Vec<_>>()
+            },
+            |pos| pos.manhattan(goal),
+            |&pos| pos == goal,
+        )
+        .unwrap();
+        assert_eq!(cost, 7);
+        assert_eq!(path.len(), 8);
+        assert_eq!(path.last(), Some(&goal));
+    }
+}
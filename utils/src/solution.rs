@@ -0,0 +1,115 @@
+use std::{
+    fmt::{self, Display},
+    str::FromStr,
+};
+
+/// The answer to one part of a puzzle, as it would be typed on the website.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Answer(String);
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+macro_rules! answer_from {
+    ($($t:ty),*) => {$(
+        impl From<$t> for Answer {
+            fn from(value: $t) -> Self {
+                Self(value.to_string())
+            }
+        }
+    )*};
+}
+
+answer_from!(i32, i64, isize, u32, u64, usize, char, &str, String);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Part::One => f.write_str("part1"),
+            Part::Two => f.write_str("part2"),
+        }
+    }
+}
+
+impl FromStr for Part {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "1" | "part1" => Ok(Part::One),
+            "2" | "part2" => Ok(Part::Two),
+            _ => anyhow::bail!("no such part: {s}, expected 1 or 2"),
+        }
+    }
+}
+
+/// Returned by the default `part1`/`part2` of a [`Solution`] that doesn't solve that part yet.
+#[derive(Debug, Clone, Copy)]
+pub struct Unsolved;
+
+impl Display for Unsolved {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("not solved yet")
+    }
+}
+
+impl std::error::Error for Unsolved {}
+
+pub trait Solution {
+    /// What `parse` turns the puzzle input into, shared by both parts.
+    type Input;
+
+    fn parse(input: &str) -> anyhow::Result<Self::Input>;
+
+    fn part1(_input: &Self::Input) -> anyhow::Result<Answer> {
+        Err(Unsolved.into())
+    }
+
+    fn part2(_input: &Self::Input) -> anyhow::Result<Answer> {
+        Err(Unsolved.into())
+    }
+
+    /// A picture of what solving `part` went through, only shown when asked for.
+    fn render(_input: &Self::Input, _part: Part) -> anyhow::Result<Option<String>> {
+        Ok(None)
+    }
+}
+
+/// A parsed input, with the type of its [`Solution`] erased.
+pub trait Puzzle {
+    fn part1(&self) -> anyhow::Result<Answer>;
+    fn part2(&self) -> anyhow::Result<Answer>;
+    fn render(&self, part: Part) -> anyhow::Result<Option<String>>;
+
+    fn solve(&self, part: Part) -> anyhow::Result<Answer> {
+        match part {
+            Part::One => self.part1(),
+            Part::Two => self.part2(),
+        }
+    }
+}
+
+pub(crate) struct Parsed<S: Solution>(pub S::Input);
+
+impl<S: Solution> Puzzle for Parsed<S> {
+    fn part1(&self) -> anyhow::Result<Answer> {
+        S::part1(&self.0)
+    }
+
+    fn part2(&self) -> anyhow::Result<Answer> {
+        S::part2(&self.0)
+    }
+
+    fn render(&self, part: Part) -> anyhow::Result<Option<String>> {
+        S::render(&self.0, part)
+    }
+}